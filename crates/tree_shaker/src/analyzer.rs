@@ -9,7 +9,7 @@ use crate::{
     ScopeContext,
   },
   tree_shaker::TreeShaker,
-  utils::{ExtraData, StatementVecData},
+  utils::{ExtraData, JsxPragma, StatementVecData},
   TreeShakeConfig,
 };
 use line_index::LineIndex;
@@ -41,6 +41,7 @@ pub struct Analyzer<'a> {
   pub pending_deps: FxHashSet<ExhaustiveCallback<'a>>,
   pub builtins: Builtins<'a>,
   pub entity_op: EntityOpHost<'a>,
+  pub jsx_pragma: JsxPragma<'a>,
 
   pub debug: usize,
 }
@@ -50,8 +51,9 @@ impl<'a> Analyzer<'a> {
     let config = tree_shaker.0.config;
     let allocator = tree_shaker.0.allocator;
     let factory = tree_shaker.0.factory;
+    let mangle_cache = tree_shaker.0.mangle_cache.take();
 
-    Analyzer {
+    let mut analyzer = Analyzer {
       tree_shaker,
       config,
       allocator,
@@ -63,15 +65,18 @@ impl<'a> Analyzer<'a> {
       referred_deps: Default::default(),
       conditional_data: Default::default(),
       loop_data: Default::default(),
-      mangler: Mangler::new(config.mangling, allocator),
+      mangler: Mangler::new(config, mangle_cache, allocator),
       named_exports: Vec::new(),
       default_export: None,
       scope_context: ScopeContext::new(factory),
       pending_deps: Default::default(),
       builtins: Builtins::new(config, factory),
       entity_op: EntityOpHost::new(allocator),
+      jsx_pragma: Default::default(),
       debug: 0,
-    }
+    };
+    analyzer.jsx_pragma = analyzer.get_jsx_pragma();
+    analyzer
   }
 
   pub fn exec_program(&mut self, node: &'a Program<'a>) {
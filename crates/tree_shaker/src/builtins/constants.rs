@@ -6,3 +6,20 @@ pub const IMPORT_META_OBJECT_ID: SymbolId = unsafe { mem::transmute(1u32) };
 pub const REACT_NAMESPACE_OBJECT_ID: SymbolId = unsafe { mem::transmute(2u32) };
 pub const REACT_JSX_RUNTIME_NAMESPACE_OBJECT_ID: SymbolId = unsafe { mem::transmute(3u32) };
 pub const OBJECT_CONSTRUCTOR_OBJECT_ID: SymbolId = unsafe { mem::transmute(4u32) };
+pub const NUMBER_CONSTRUCTOR_OBJECT_ID: SymbolId = unsafe { mem::transmute(5u32) };
+pub const REFLECT_OBJECT_ID: SymbolId = unsafe { mem::transmute(6u32) };
+pub const REACT_CHILDREN_OBJECT_ID: SymbolId = unsafe { mem::transmute(7u32) };
+pub const REACT_JSX_DEV_RUNTIME_NAMESPACE_OBJECT_ID: SymbolId = unsafe { mem::transmute(8u32) };
+pub const PREACT_NAMESPACE_OBJECT_ID: SymbolId = unsafe { mem::transmute(9u32) };
+pub const VUE_NAMESPACE_OBJECT_ID: SymbolId = unsafe { mem::transmute(10u32) };
+pub const SOLID_WEB_NAMESPACE_OBJECT_ID: SymbolId = unsafe { mem::transmute(11u32) };
+pub const REACT_COMPONENT_OBJECT_ID: SymbolId = unsafe { mem::transmute(12u32) };
+pub const REACT_PURE_COMPONENT_OBJECT_ID: SymbolId = unsafe { mem::transmute(13u32) };
+pub const REACT_COMPONENT_PROTOTYPE_OBJECT_ID: SymbolId = unsafe { mem::transmute(14u32) };
+pub const REACT_DOM_NAMESPACE_OBJECT_ID: SymbolId = unsafe { mem::transmute(15u32) };
+pub const REACT_DOM_CLIENT_NAMESPACE_OBJECT_ID: SymbolId = unsafe { mem::transmute(16u32) };
+pub const REACT_DOM_SERVER_NAMESPACE_OBJECT_ID: SymbolId = unsafe { mem::transmute(17u32) };
+pub const REACT_DOM_ROOT_OBJECT_ID: SymbolId = unsafe { mem::transmute(18u32) };
+pub const CLSX_NAMESPACE_OBJECT_ID: SymbolId = unsafe { mem::transmute(19u32) };
+pub const TAILWIND_MERGE_NAMESPACE_OBJECT_ID: SymbolId = unsafe { mem::transmute(20u32) };
+pub const SOLID_H_NAMESPACE_OBJECT_ID: SymbolId = unsafe { mem::transmute(21u32) };
@@ -0,0 +1,153 @@
+use crate::{
+  builtins::Builtins,
+  entity::{Entity, LiteralEntity},
+  init_map,
+};
+
+const URI_UNRESERVED_MARKS: &str = "-_.!~*'()";
+const URI_RESERVED: &str = ";/?:@&=+$,#";
+const ESCAPE_UNESCAPED: &str = "@*_+-./";
+const BASE64_ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+impl<'a> Builtins<'a> {
+  pub fn init_global_encoding_functions(&mut self) {
+    init_map!(self.globals, {
+      "encodeURIComponent" => self.create_string_transform_fn("encodeURIComponent", |s| Some(encode_uri(s, false)), "URI malformed"),
+      "encodeURI" => self.create_string_transform_fn("encodeURI", |s| Some(encode_uri(s, true)), "URI malformed"),
+      "decodeURIComponent" => self.create_string_transform_fn("decodeURIComponent", |s| decode_uri(s, false), "URI malformed"),
+      "decodeURI" => self.create_string_transform_fn("decodeURI", |s| decode_uri(s, true), "URI malformed"),
+      "escape" => self.create_string_transform_fn("escape", |s| Some(escape(s)), ""),
+      "btoa" => self.create_string_transform_fn("btoa", btoa, "Invalid character"),
+      "atob" => self.create_string_transform_fn("atob", atob, "The string to be decoded is not correctly encoded"),
+    })
+  }
+
+  /// Creates a function which converts its first argument to string, and folds the result if the
+  /// argument is a known string. An empty `error` means the transform never throws.
+  fn create_string_transform_fn(
+    &self,
+    name: &'static str,
+    transform: fn(&str) -> Option<String>,
+    error: &'static str,
+  ) -> Entity<'a> {
+    self.factory.implemented_builtin_fn(name, move |analyzer, dep, _, args| {
+      let arg = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+      let str = arg.get_to_string(analyzer);
+
+      if let Some(LiteralEntity::String(str, _)) = str.get_literal(analyzer) {
+        if let Some(result) = transform(str) {
+          let result = analyzer.allocator.alloc(result);
+          return analyzer.factory.computed(analyzer.factory.string(result), arg);
+        }
+        analyzer.consume((dep, arg));
+        analyzer.thrown_builtin_error(format!("{name}: {error}"));
+      } else if !error.is_empty() {
+        analyzer.consume((dep, arg));
+        analyzer.may_throw();
+      }
+
+      analyzer.factory.computed_unknown_string(arg)
+    })
+  }
+}
+
+fn encode_uri(s: &str, is_full_uri: bool) -> String {
+  let mut result = String::with_capacity(s.len());
+  let mut buf = [0u8; 4];
+  for c in s.chars() {
+    if c.is_ascii_alphanumeric()
+      || URI_UNRESERVED_MARKS.contains(c)
+      || (is_full_uri && URI_RESERVED.contains(c))
+    {
+      result.push(c);
+    } else {
+      for byte in c.encode_utf8(&mut buf).bytes() {
+        result.push_str(&format!("%{byte:02X}"));
+      }
+    }
+  }
+  result
+}
+
+fn decode_uri(s: &str, is_full_uri: bool) -> Option<String> {
+  let bytes = s.as_bytes();
+  let mut result = Vec::with_capacity(bytes.len());
+  let mut index = 0;
+  while index < bytes.len() {
+    if bytes[index] == b'%' {
+      let hex = s.get(index + 1..index + 3)?;
+      // `from_str_radix` accepts a sign, which is not a hex digit
+      if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+      }
+      let byte = u8::from_str_radix(hex, 16).ok()?;
+      if is_full_uri && byte.is_ascii() && URI_RESERVED.contains(byte as char) {
+        // Reserved characters are kept escaped by `decodeURI`
+        result.extend_from_slice(&bytes[index..index + 3]);
+      } else {
+        result.push(byte);
+      }
+      index += 3;
+    } else {
+      result.push(bytes[index]);
+      index += 1;
+    }
+  }
+  String::from_utf8(result).ok()
+}
+
+fn escape(s: &str) -> String {
+  let mut result = String::with_capacity(s.len());
+  for unit in s.encode_utf16() {
+    match char::from_u32(unit as u32) {
+      Some(c) if c.is_ascii_alphanumeric() || ESCAPE_UNESCAPED.contains(c) => result.push(c),
+      _ if unit < 256 => result.push_str(&format!("%{unit:02X}")),
+      _ => result.push_str(&format!("%u{unit:04X}")),
+    }
+  }
+  result
+}
+
+fn btoa(s: &str) -> Option<String> {
+  let bytes = s.chars().map(|c| u8::try_from(c as u32).ok()).collect::<Option<Vec<_>>>()?;
+  let mut result = String::with_capacity((bytes.len() + 2) / 3 * 4);
+  for chunk in bytes.chunks(3) {
+    let n = chunk.iter().enumerate().fold(0u32, |n, (i, b)| n | (*b as u32) << (16 - i * 8));
+    for i in 0..4 {
+      if i <= chunk.len() {
+        result.push(BASE64_ALPHABET[(n >> (18 - i * 6)) as usize & 0x3f] as char);
+      } else {
+        result.push('=');
+      }
+    }
+  }
+  Some(result)
+}
+
+fn atob(s: &str) -> Option<String> {
+  let mut s = s.chars().filter(|c| !c.is_ascii_whitespace()).collect::<String>();
+  if s.len() % 4 == 0 {
+    for _ in 0..2 {
+      if s.ends_with('=') {
+        s.pop();
+      }
+    }
+  }
+  if s.len() % 4 == 1 {
+    return None;
+  }
+  let mut result = String::with_capacity(s.len() * 3 / 4);
+  let mut n = 0u32;
+  let mut bits = 0;
+  for c in s.bytes() {
+    let value = BASE64_ALPHABET.iter().position(|b| *b == c)?;
+    n = (n << 6) | value as u32;
+    bits += 6;
+    if bits >= 8 {
+      bits -= 8;
+      result.push(char::from((n >> bits) as u8));
+      n &= (1 << bits) - 1;
+    }
+  }
+  Some(result)
+}
@@ -1,11 +1,21 @@
 mod constants;
+mod encoding;
+mod number_constructor;
 mod object_constructor;
+mod proxy_constructor;
+mod reflect;
+mod typed_arrays;
 
 use super::Builtins;
 
 impl<'a> Builtins<'a> {
   pub fn init_globals(&mut self) {
     self.init_global_constants();
+    self.init_global_encoding_functions();
+    self.init_number_constructor();
     self.init_object_constructor();
+    self.init_proxy_constructor();
+    self.init_reflect();
+    self.init_typed_arrays();
   }
 }
@@ -0,0 +1,136 @@
+use crate::{
+  builtins::{constants::NUMBER_CONSTRUCTOR_OBJECT_ID, Builtins},
+  entity::{Entity, LiteralEntity, ObjectPropertyValue},
+  init_map, init_namespace,
+};
+
+impl<'a> Builtins<'a> {
+  pub fn init_number_constructor(&mut self) {
+    let factory = self.factory;
+
+    let object =
+      factory.builtin_object(NUMBER_CONSTRUCTOR_OBJECT_ID, &self.prototypes.function, false);
+    object.init_rest(ObjectPropertyValue::Field(factory.immutable_unknown, true));
+
+    let parse_float = self.create_parse_float_impl();
+
+    init_namespace!(object, {
+      "prototype" => factory.immutable_unknown,
+      "parseFloat" => parse_float,
+      "isFinite" => self.create_is_finite_impl("Number.isFinite", true),
+    });
+
+    let number =
+      factory.implemented_builtin_fn_with_object("Number", object, |analyzer, dep, _, args| {
+        let (args, _, dep) = args.destruct_as_array(analyzer, dep, 1, false);
+        let arg = args[0];
+        if arg.test_is_undefined() == Some(true) {
+          // `Number()` is 0, while `Number(undefined)` is NaN
+          return analyzer.factory.computed_unknown_number((dep, arg));
+        }
+        let numeric = arg.get_to_numeric(analyzer);
+        match numeric.get_literal(analyzer) {
+          Some(LiteralEntity::Number(_, _) | LiteralEntity::NaN | LiteralEntity::Infinity(_)) => {
+            analyzer.factory.computed(numeric, (dep, arg))
+          }
+          _ => analyzer.factory.computed_unknown_number((dep, arg)),
+        }
+      });
+
+    init_map!(self.globals, {
+      "Number" => number,
+      "parseFloat" => parse_float,
+      "isFinite" => self.create_is_finite_impl("isFinite", false),
+    });
+  }
+
+  fn create_parse_float_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn("parseFloat", |analyzer, dep, _, args| {
+      let arg = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+      let str = arg.get_to_string(analyzer);
+
+      if let Some(LiteralEntity::String(str, _)) = str.get_literal(analyzer) {
+        let value = parse_float(str);
+        let result = if value.is_nan() {
+          analyzer.factory.nan
+        } else if value.is_infinite() {
+          analyzer.factory.infinity(value.is_sign_positive())
+        } else {
+          analyzer.factory.number(value, None)
+        };
+        analyzer.factory.computed(result, arg)
+      } else {
+        analyzer.factory.computed_unknown_number(arg)
+      }
+    })
+  }
+
+  /// `Number.isFinite` does not convert its argument, while the global `isFinite` does.
+  fn create_is_finite_impl(&self, name: &'static str, strict: bool) -> Entity<'a> {
+    self.factory.implemented_builtin_fn(name, move |analyzer, dep, _, args| {
+      let arg = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+      let numeric = if strict { arg } else { arg.get_to_numeric(analyzer) };
+
+      let result = match numeric.get_literal(analyzer) {
+        Some(LiteralEntity::Number(value, _)) => Some(value.0.is_finite()),
+        Some(LiteralEntity::NaN | LiteralEntity::Infinity(_)) => Some(false),
+        Some(_) if strict => Some(false),
+        _ => None,
+      };
+
+      match result {
+        Some(result) => analyzer.factory.computed(analyzer.factory.boolean(result), arg),
+        None => analyzer.factory.computed_unknown_boolean(arg),
+      }
+    })
+  }
+}
+
+/// Parses the longest prefix of the string which is a valid decimal literal.
+fn parse_float(s: &str) -> f64 {
+  let s = s.trim_start();
+  let bytes = s.as_bytes();
+  let mut end = 0;
+
+  if matches!(bytes.first(), Some(b'+' | b'-')) {
+    end += 1;
+  }
+  if s[end..].starts_with("Infinity") {
+    return if s.starts_with('-') { f64::NEG_INFINITY } else { f64::INFINITY };
+  }
+
+  let digits_start = end;
+  while end < bytes.len() && bytes[end].is_ascii_digit() {
+    end += 1;
+  }
+  let mut has_digits = end > digits_start;
+  if end < bytes.len() && bytes[end] == b'.' {
+    let fraction_start = end + 1;
+    let mut fraction_end = fraction_start;
+    while fraction_end < bytes.len() && bytes[fraction_end].is_ascii_digit() {
+      fraction_end += 1;
+    }
+    if has_digits || fraction_end > fraction_start {
+      has_digits = true;
+      end = fraction_end;
+    }
+  }
+  if !has_digits {
+    return f64::NAN;
+  }
+  if end < bytes.len() && matches!(bytes[end], b'e' | b'E') {
+    let mut exponent_end = end + 1;
+    if matches!(bytes.get(exponent_end), Some(b'+' | b'-')) {
+      exponent_end += 1;
+    }
+    let exponent_digits_start = exponent_end;
+    while exponent_end < bytes.len() && bytes[exponent_end].is_ascii_digit() {
+      exponent_end += 1;
+    }
+    if exponent_end > exponent_digits_start {
+      end = exponent_end;
+    }
+  }
+
+  s[..end].parse().unwrap_or(f64::NAN)
+}
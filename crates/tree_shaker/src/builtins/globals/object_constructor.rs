@@ -1,5 +1,7 @@
 use crate::{
+  analyzer::Analyzer,
   builtins::{constants::OBJECT_CONSTRUCTOR_OBJECT_ID, Builtins},
+  consumable::Consumable,
   entity::{Entity, ObjectPropertyValue, TypeofResult},
   init_namespace,
 };
@@ -19,6 +21,8 @@ impl<'a> Builtins<'a> {
       "keys" => self.create_object_keys_impl(),
       "values" => self.create_object_values_impl(),
       "entries" => self.create_object_entries_impl(),
+      "defineProperty" => self.create_object_define_property_impl(),
+      "defineProperties" => self.create_object_define_properties_impl(),
     });
 
     self.globals.borrow_mut().insert("Object", object);
@@ -107,4 +111,68 @@ impl<'a> Builtins<'a> {
       analyzer.factory.computed(array, deps)
     })
   }
+
+  fn create_object_define_property_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn("Object.defineProperty", |analyzer, dep, _, args| {
+      let args = args.destruct_as_array(analyzer, dep, 3, false).0;
+      let (object, key, descriptor) = (args[0], args[1], args[2]);
+      let key = key.get_to_property_key(analyzer);
+      define_property_by_descriptor(analyzer, dep, object, key, descriptor);
+      object
+    })
+  }
+
+  fn create_object_define_properties_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn("Object.defineProperties", |analyzer, dep, _, args| {
+      let args = args.destruct_as_array(analyzer, dep, 2, false).0;
+      let (object, descriptors) = (args[0], args[1]);
+      let (properties, deps) = descriptors.enumerate_properties(analyzer, dep);
+      for (definite, key, descriptor) in properties {
+        if !definite {
+          analyzer.push_indeterminate_cf_scope();
+        }
+        define_property_by_descriptor(analyzer, deps, object, key, descriptor);
+        if !definite {
+          analyzer.pop_cf_scope();
+        }
+      }
+      analyzer.factory.computed(object, deps)
+    })
+  }
+}
+
+fn define_property_by_descriptor<'a>(
+  analyzer: &mut Analyzer<'a>,
+  dep: Consumable<'a>,
+  object: Entity<'a>,
+  key: Entity<'a>,
+  descriptor: Entity<'a>,
+) {
+  let mut get_field = |name| descriptor.get_property(analyzer, dep, analyzer.factory.string(name));
+  let value = get_field("value");
+  let writable = get_field("writable");
+  let getter = get_field("get");
+  let setter = get_field("set");
+  let enumerable = get_field("enumerable");
+  let configurable = get_field("configurable");
+
+  let property = match (getter.test_is_undefined(), setter.test_is_undefined()) {
+    (Some(true), Some(true)) => {
+      writable.test_truthy().map(|writable| ObjectPropertyValue::Field(value, !writable))
+    }
+    (Some(getter_undefined), Some(setter_undefined)) => Some(ObjectPropertyValue::Property(
+      (!getter_undefined).then_some(getter),
+      (!setter_undefined).then_some(setter),
+    )),
+    _ => None,
+  };
+
+  if let Some(property) = property {
+    let dep = analyzer.consumable((dep, writable, enumerable, configurable));
+    object.define_property(analyzer, dep, key, property, enumerable.test_truthy() == Some(true));
+  } else {
+    analyzer.consume((dep, object, key, descriptor));
+    analyzer.may_throw();
+    analyzer.refer_to_global();
+  }
 }
@@ -0,0 +1,27 @@
+use crate::{
+  builtins::Builtins,
+  entity::{Entity, TypeofResult},
+};
+
+impl<'a> Builtins<'a> {
+  pub fn init_proxy_constructor(&mut self) {
+    let proxy = self.factory.implemented_builtin_constructor("Proxy", |analyzer, dep, args| {
+      let args = args.destruct_as_array(analyzer, dep, 2, false).0;
+      let (target, handler) = (args[0], args[1]);
+
+      let is_object = |entity: &Entity<'a>| {
+        (TypeofResult::Object | TypeofResult::Function).contains(entity.test_typeof())
+          && entity.test_nullish() == Some(false)
+      };
+      if !is_object(&target) || !is_object(&handler) {
+        analyzer.consume((dep, target, handler));
+        analyzer.may_throw();
+        return analyzer.factory.unknown();
+      }
+
+      analyzer.factory.computed(analyzer.factory.proxy(target, handler), dep)
+    });
+
+    self.globals.insert("Proxy", proxy);
+  }
+}
@@ -0,0 +1,83 @@
+use crate::{
+  builtins::{constants::REFLECT_OBJECT_ID, Builtins},
+  entity::{Entity, ObjectPropertyValue},
+  init_namespace,
+};
+
+impl<'a> Builtins<'a> {
+  pub fn init_reflect(&mut self) {
+    let factory = self.factory;
+
+    let object = factory.builtin_object(REFLECT_OBJECT_ID, &self.prototypes.object, false);
+    object.init_rest(ObjectPropertyValue::Field(factory.immutable_unknown, true));
+
+    init_namespace!(object, {
+      "get" => self.create_reflect_get_impl(),
+      "set" => self.create_reflect_set_impl(),
+      "has" => self.create_reflect_has_impl(),
+      "deleteProperty" => self.create_reflect_delete_property_impl(),
+      "ownKeys" => self.create_reflect_own_keys_impl(),
+      "apply" => self.create_reflect_apply_impl(),
+      "construct" => self.create_reflect_construct_impl(),
+    });
+
+    self.globals.insert("Reflect", object);
+  }
+
+  fn create_reflect_get_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn("Reflect.get", |analyzer, dep, _, args| {
+      let args = args.destruct_as_array(analyzer, dep, 2, false).0;
+      let key = args[1].get_to_property_key(analyzer);
+      args[0].get_property(analyzer, dep, key)
+    })
+  }
+
+  fn create_reflect_set_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn("Reflect.set", |analyzer, dep, _, args| {
+      let args = args.destruct_as_array(analyzer, dep, 3, false).0;
+      let key = args[1].get_to_property_key(analyzer);
+      args[0].set_property(analyzer, dep, key, args[2]);
+      analyzer.factory.computed_unknown_boolean(dep)
+    })
+  }
+
+  fn create_reflect_has_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn("Reflect.has", |analyzer, dep, _, args| {
+      let args = args.destruct_as_array(analyzer, dep, 2, false).0;
+      // Same as the `in` operator
+      analyzer.factory.computed_unknown_boolean((dep, args[0], args[1]))
+    })
+  }
+
+  fn create_reflect_delete_property_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn("Reflect.deleteProperty", |analyzer, dep, _, args| {
+      let args = args.destruct_as_array(analyzer, dep, 2, false).0;
+      let key = args[1].get_to_property_key(analyzer);
+      args[0].delete_property(analyzer, dep, key);
+      analyzer.factory.computed_unknown_boolean(dep)
+    })
+  }
+
+  fn create_reflect_own_keys_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn("Reflect.ownKeys", |analyzer, dep, _, args| {
+      let object = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+      // Unlike enumerating, the non-enumerable and symbol keys are included, which are not tracked
+      analyzer.consume((dep, object));
+      analyzer.factory.unknown()
+    })
+  }
+
+  fn create_reflect_apply_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn("Reflect.apply", |analyzer, dep, _, args| {
+      let args = args.destruct_as_array(analyzer, dep, 3, false).0;
+      args[0].call(analyzer, dep, args[1], args[2])
+    })
+  }
+
+  fn create_reflect_construct_impl(&self) -> Entity<'a> {
+    self.factory.implemented_builtin_fn("Reflect.construct", |analyzer, dep, _, args| {
+      let args = args.destruct_as_array(analyzer, dep, 2, false).0;
+      args[0].construct(analyzer, dep, args[1])
+    })
+  }
+}
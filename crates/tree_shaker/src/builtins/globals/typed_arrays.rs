@@ -0,0 +1,136 @@
+use crate::{
+  analyzer::Analyzer,
+  builtins::Builtins,
+  consumable::Consumable,
+  entity::{Entity, LiteralEntity, ObjectEntity, ObjectPropertyValue, TypeofResult},
+};
+use oxc::ast::ast::PropertyKind;
+
+const TYPED_ARRAYS: [(&str, usize); 11] = [
+  ("Int8Array", 1),
+  ("Uint8Array", 1),
+  ("Uint8ClampedArray", 1),
+  ("Int16Array", 2),
+  ("Uint16Array", 2),
+  ("Int32Array", 4),
+  ("Uint32Array", 4),
+  ("Float32Array", 4),
+  ("Float64Array", 8),
+  ("BigInt64Array", 8),
+  ("BigUint64Array", 8),
+];
+
+impl<'a> Builtins<'a> {
+  pub fn init_typed_arrays(&mut self) {
+    let factory = self.factory;
+
+    for (name, bytes_per_element) in TYPED_ARRAYS {
+      let constructor =
+        factory.implemented_builtin_constructor(name, move |analyzer, dep, args| {
+          let arg = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+
+          if may_be_buffer(arg) {
+            // May be a view of a buffer, whose memory is shared
+            analyzer.consume((dep, arg));
+            return analyzer.factory.unknown();
+          }
+
+          let (length, byte_length) = match to_index(analyzer, dep, arg) {
+            Some(length) => (
+              analyzer.factory.computed(analyzer.factory.number(length as f64, None), arg),
+              analyzer
+                .factory
+                .computed(analyzer.factory.number((length * bytes_per_element) as f64, None), arg),
+            ),
+            None => {
+              let length = analyzer.factory.computed_unknown_number(arg);
+              (length, length)
+            }
+          };
+
+          let object = new_binary_data_object(analyzer);
+          object.init_property(
+            analyzer,
+            PropertyKind::Init,
+            analyzer.factory.string("length"),
+            length,
+            true,
+          );
+          object.init_property(
+            analyzer,
+            PropertyKind::Init,
+            analyzer.factory.string("byteLength"),
+            byte_length,
+            true,
+          );
+          analyzer.factory.computed(object, dep)
+        });
+      self.globals.insert(name, constructor);
+    }
+
+    let array_buffer =
+      factory.implemented_builtin_constructor("ArrayBuffer", |analyzer, dep, args| {
+        let arg = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+        let byte_length = match to_index(analyzer, dep, arg) {
+          Some(length) => {
+            analyzer.factory.computed(analyzer.factory.number(length as f64, None), arg)
+          }
+          None => analyzer.factory.computed_unknown_number(arg),
+        };
+
+        let object = new_binary_data_object(analyzer);
+        object.init_property(
+          analyzer,
+          PropertyKind::Init,
+          analyzer.factory.string("byteLength"),
+          byte_length,
+          true,
+        );
+        analyzer.factory.computed(object, dep)
+      });
+    self.globals.insert("ArrayBuffer", array_buffer);
+
+    let data_view = factory.implemented_builtin_constructor("DataView", |analyzer, dep, args| {
+      let buffer = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+      if !TypeofResult::Object.contains(buffer.test_typeof()) {
+        analyzer.may_throw();
+      }
+
+      // The view shares memory with the buffer, so writes to it can't be tracked
+      analyzer.consume((dep, args));
+      analyzer.factory.unknown()
+    });
+    self.globals.insert("DataView", data_view);
+  }
+}
+
+fn may_be_buffer(value: Entity) -> bool {
+  value.test_typeof().intersects(TypeofResult::Object | TypeofResult::Function)
+}
+
+fn new_binary_data_object<'a>(analyzer: &mut Analyzer<'a>) -> &'a mut ObjectEntity<'a> {
+  let object = analyzer.new_empty_object(&analyzer.builtins.prototypes.object, None);
+  object.init_rest(ObjectPropertyValue::Field(analyzer.factory.immutable_unknown, false));
+  object
+}
+
+/// The `ToIndex` abstract operation. Returns `None` if the index is unknown.
+fn to_index<'a>(
+  analyzer: &mut Analyzer<'a>,
+  dep: Consumable<'a>,
+  value: Entity<'a>,
+) -> Option<usize> {
+  let index = match value.get_to_numeric(analyzer).get_literal(analyzer)? {
+    LiteralEntity::Number(value, _) => value.0.trunc(),
+    LiteralEntity::NaN => 0.0,
+    LiteralEntity::Infinity(_) => -1.0,
+    _ => return None,
+  };
+  if (0.0..=(u32::MAX as f64)).contains(&index) {
+    Some(index as usize)
+  } else {
+    analyzer.consume((dep, value));
+    analyzer.thrown_builtin_error("Invalid array length");
+    None
+  }
+}
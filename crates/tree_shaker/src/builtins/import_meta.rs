@@ -1,16 +1,27 @@
 use super::{constants::IMPORT_META_OBJECT_ID, prototypes::BuiltinPrototypes, Builtins};
-use crate::entity::{Entity, EntityFactory, ObjectProperty, ObjectPropertyValue};
+use crate::{
+  entity::{Entity, EntityFactory, ObjectEntity, ObjectProperty, ObjectPropertyValue},
+  utils::parse_define_value,
+  TreeShakeConfig,
+};
+use rustc_hash::FxHashMap;
 
 impl<'a> Builtins<'a> {
   pub fn create_import_meta(
+    config: &'a TreeShakeConfig,
     factory: &'a EntityFactory<'a>,
     prototypes: &'a BuiltinPrototypes<'a>,
   ) -> Entity<'a> {
-    let object = factory.builtin_object(IMPORT_META_OBJECT_ID, &prototypes.null, true);
-    object.init_rest(ObjectPropertyValue::Property(
-      Some(factory.immutable_unknown),
-      Some(factory.immutable_unknown),
-    ));
+    let object = if config.import_meta.is_empty() {
+      let object = factory.builtin_object(IMPORT_META_OBJECT_ID, &prototypes.null, true);
+      object.init_rest(ObjectPropertyValue::Property(
+        Some(factory.immutable_unknown),
+        Some(factory.immutable_unknown),
+      ));
+      object
+    } else {
+      create_unknown_object(factory, prototypes)
+    };
 
     // import.meta.url
     object.string_keyed.borrow_mut().insert(
@@ -24,10 +35,68 @@ impl<'a> Builtins<'a> {
           None,
         )],
         non_existent: Default::default(),
+        non_enumerable: Default::default(),
         mangling: None,
       },
     );
 
+    // Configured properties, which are validated before tree shaking. Sorted so that `env` is
+    // applied before `env.MODE`.
+    let mut paths = config.import_meta.iter().collect::<Vec<_>>();
+    paths.sort_unstable_by_key(|(path, _)| path.as_str());
+    let mut objects = FxHashMap::<&'a str, &'a ObjectEntity<'a>>::default();
+    objects.insert("", object);
+    for (path, value) in paths {
+      let (parent, key) = path.rsplit_once('.').unwrap_or(("", path));
+      let parent = get_nested_object(factory, prototypes, &mut objects, parent);
+      // An absent property is modelled as `undefined`, which is what reading it gives.
+      let value = match value {
+        Some(value) => factory.alloc(parse_define_value(value).unwrap()),
+        None => factory.undefined,
+      };
+      set_field(parent, key, value);
+    }
+
     object
   }
 }
+
+fn create_unknown_object<'a>(
+  factory: &'a EntityFactory<'a>,
+  prototypes: &'a BuiltinPrototypes<'a>,
+) -> &'a ObjectEntity<'a> {
+  let object = factory.builtin_object(IMPORT_META_OBJECT_ID, &prototypes.null, true);
+  // Reading an unknown property must not consume the object, or the configured ones get lost
+  object.init_rest(ObjectPropertyValue::Field(factory.immutable_unknown, false));
+  object
+}
+
+/// Gets the object at `path` relative to `import.meta`, creating the missing ones on the way.
+fn get_nested_object<'a>(
+  factory: &'a EntityFactory<'a>,
+  prototypes: &'a BuiltinPrototypes<'a>,
+  objects: &mut FxHashMap<&'a str, &'a ObjectEntity<'a>>,
+  path: &'a str,
+) -> &'a ObjectEntity<'a> {
+  if let Some(object) = objects.get(path) {
+    return object;
+  }
+  let (parent, key) = path.rsplit_once('.').unwrap_or(("", path));
+  let parent = get_nested_object(factory, prototypes, objects, parent);
+  let object = create_unknown_object(factory, prototypes);
+  set_field(parent, key, object);
+  objects.insert(path, object);
+  object
+}
+
+/// Configured properties are writable, as they are on the real `import.meta`.
+fn set_field<'a>(object: &'a ObjectEntity<'a>, key: &'a str, value: Entity<'a>) {
+  object.string_keyed.borrow_mut().insert(
+    key,
+    ObjectProperty {
+      definite: true,
+      possible_values: vec![ObjectPropertyValue::Field(value, false)],
+      ..Default::default()
+    },
+  );
+}
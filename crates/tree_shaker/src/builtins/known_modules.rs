@@ -1,7 +1,15 @@
 use super::{
+  preact::create_preact_namespace,
   react::{
-    create_class_names_namespace, create_react_jsx_runtime_namespace, create_react_namespace,
+    create_class_names_namespace, create_clsx_namespace, create_react_jsx_dev_runtime_namespace,
+    create_react_jsx_runtime_namespace, create_react_namespace, create_tailwind_merge_namespace,
   },
+  react_dom::{
+    create_react_dom_client_namespace, create_react_dom_namespace,
+    create_react_dom_server_namespace,
+  },
+  solid::{create_solid_h_namespace, create_solid_web_namespace},
+  vue::create_vue_namespace,
   Builtins,
 };
 use crate::entity::Entity;
@@ -20,14 +28,58 @@ impl<'a> Builtins<'a> {
       let value = create_react_namespace(self.factory, self.prototypes);
       KnownModule { namespace: value, default: value }
     });
-    known_modules.insert("react/jsx-runtime", {
+    let jsx_runtime = {
       let value = create_react_jsx_runtime_namespace(self.factory, self.prototypes);
       KnownModule { namespace: value, default: value }
+    };
+    known_modules.insert("react/jsx-runtime", jsx_runtime);
+    known_modules.insert("react/jsx-dev-runtime", {
+      let value = create_react_jsx_dev_runtime_namespace(self.factory, self.prototypes);
+      KnownModule { namespace: value, default: value }
+    });
+    known_modules.insert("react-dom", {
+      let value = create_react_dom_namespace(self.factory, self.prototypes);
+      KnownModule { namespace: value, default: value }
+    });
+    known_modules.insert("react-dom/client", {
+      let value = create_react_dom_client_namespace(self.factory, self.prototypes);
+      KnownModule { namespace: value, default: value }
+    });
+    known_modules.insert("react-dom/server", {
+      let value = create_react_dom_server_namespace(self.factory, self.prototypes);
+      KnownModule { namespace: value, default: value }
+    });
+    known_modules.insert("preact", {
+      let value = create_preact_namespace(self.factory, self.prototypes);
+      KnownModule { namespace: value, default: value }
+    });
+    // These automatic runtimes share the signature of React's
+    known_modules.insert("preact/jsx-runtime", jsx_runtime);
+    known_modules.insert("vue/jsx-runtime", jsx_runtime);
+    known_modules.insert("vue", {
+      let value = create_vue_namespace(self.factory, self.prototypes);
+      KnownModule { namespace: value, default: value }
+    });
+    known_modules.insert("solid-js/web", {
+      let value = create_solid_web_namespace(self.factory, self.prototypes);
+      KnownModule { namespace: value, default: value }
+    });
+    known_modules.insert("solid-js/h", {
+      let (namespace, default) = create_solid_h_namespace(self.factory, self.prototypes);
+      KnownModule { namespace, default }
     });
     known_modules.insert("classnames", {
       let value = create_class_names_namespace(self.factory, self.prototypes);
       KnownModule { namespace: value, default: value }
     });
+    known_modules.insert("clsx", {
+      let (namespace, default) = create_clsx_namespace(self.factory, self.prototypes);
+      KnownModule { namespace, default }
+    });
+    known_modules.insert("tailwind-merge", {
+      let value = create_tailwind_merge_namespace(self.factory, self.prototypes);
+      KnownModule { namespace: value, default: value }
+    });
   }
 
   pub fn get_known_module(&self, name: &str) -> Option<KnownModule<'a>> {
@@ -2,9 +2,13 @@ mod constants;
 mod globals;
 mod import_meta;
 mod known_modules;
+mod preact;
 mod prototypes;
 mod react;
+mod react_dom;
+mod solid;
 mod utils;
+mod vue;
 
 use crate::{
   entity::{Entity, EntityFactory},
@@ -36,7 +40,7 @@ impl<'a> Builtins<'a> {
       factory,
 
       prototypes,
-      import_meta: Self::create_import_meta(factory, prototypes),
+      import_meta: Self::create_import_meta(config, factory, prototypes),
       globals: Default::default(),       // Initialize later
       known_modules: Default::default(), // Initialize later
 
@@ -0,0 +1,33 @@
+use super::{
+  constants::PREACT_NAMESPACE_OBJECT_ID,
+  prototypes::BuiltinPrototypes,
+  react::{
+    create_react_clone_element_impl, create_react_create_element_impl, create_react_fragment_impl,
+    create_react_is_valid_element_impl,
+  },
+};
+use crate::{
+  entity::{Entity, EntityFactory, ObjectPropertyValue},
+  init_namespace,
+};
+
+pub fn create_preact_namespace<'a>(
+  factory: &'a EntityFactory<'a>,
+  prototypes: &'a BuiltinPrototypes<'a>,
+) -> Entity<'a> {
+  let namespace = factory.builtin_object(PREACT_NAMESPACE_OBJECT_ID, &prototypes.null, false);
+  namespace.init_rest(ObjectPropertyValue::Field(factory.immutable_unknown, true));
+
+  // `h` has the same signature as `React.createElement`
+  let create_element = create_react_create_element_impl(factory);
+
+  init_namespace!(namespace, {
+    "h" => create_element,
+    "createElement" => create_element,
+    "Fragment" => create_react_fragment_impl(factory),
+    "cloneElement" => create_react_clone_element_impl(factory),
+    "isValidElement" => create_react_is_valid_element_impl(factory),
+  });
+
+  namespace
+}
@@ -0,0 +1,81 @@
+use crate::{
+  analyzer::Analyzer,
+  builtins::{constants::REACT_CHILDREN_OBJECT_ID, prototypes::BuiltinPrototypes},
+  consumable::Consumable,
+  entity::{Entity, EntityFactory, ObjectPropertyValue},
+  init_namespace,
+};
+
+pub fn create_react_children_namespace<'a>(
+  factory: &'a EntityFactory<'a>,
+  prototypes: &'a BuiltinPrototypes<'a>,
+) -> Entity<'a> {
+  let namespace = factory.builtin_object(REACT_CHILDREN_OBJECT_ID, &prototypes.null, false);
+  namespace.init_rest(ObjectPropertyValue::Field(factory.immutable_unknown, true));
+
+  init_namespace!(namespace, {
+    "map" => create_react_children_map_impl(factory),
+    "forEach" => create_react_children_for_each_impl(factory),
+    "count" => create_react_children_count_impl(factory),
+    "toArray" => create_react_children_to_array_impl(factory),
+    "only" => create_react_children_only_impl(factory),
+  });
+
+  namespace
+}
+
+fn create_react_children_map_impl<'a>(factory: &'a EntityFactory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("React::Children::map", |analyzer, dep, _this, args| {
+    let (children, result) = call_children_callback(analyzer, dep, args);
+    analyzer.factory.computed_unknown((children, result))
+  })
+}
+
+fn create_react_children_for_each_impl<'a>(factory: &'a EntityFactory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("React::Children::forEach", |analyzer, dep, _this, args| {
+    call_children_callback(analyzer, dep, args);
+    analyzer.factory.undefined
+  })
+}
+
+/// The callback is called eagerly for each child, which is unknown, and may never be called.
+fn call_children_callback<'a>(
+  analyzer: &mut Analyzer<'a>,
+  dep: Consumable<'a>,
+  args: Entity<'a>,
+) -> (Entity<'a>, Entity<'a>) {
+  let [children, callback, this_arg] = args.destruct_as_array(analyzer, dep, 3, false).0[..] else {
+    unreachable!()
+  };
+  let child = analyzer.factory.computed_unknown(children);
+  let callback_args =
+    analyzer.factory.arguments(vec![(false, child), (false, analyzer.factory.unknown_number)]);
+  analyzer.push_indeterminate_cf_scope();
+  let result = callback.call(analyzer, dep, this_arg, callback_args);
+  analyzer.pop_cf_scope();
+  (children, result)
+}
+
+fn create_react_children_count_impl<'a>(factory: &'a EntityFactory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("React::Children::count", |analyzer, dep, _this, args| {
+    let children = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+    analyzer.factory.computed_unknown_number(children)
+  })
+}
+
+fn create_react_children_to_array_impl<'a>(factory: &'a EntityFactory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("React::Children::toArray", |analyzer, dep, _this, args| {
+    let children = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+    analyzer.factory.computed_unknown(children)
+  })
+}
+
+fn create_react_children_only_impl<'a>(factory: &'a EntityFactory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("React::Children::only", |analyzer, dep, _this, args| {
+    let children = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+    // Throws if `children` is not a single element
+    analyzer.consume(children);
+    analyzer.may_throw();
+    analyzer.factory.unknown()
+  })
+}
@@ -0,0 +1,176 @@
+use crate::{
+  analyzer::Analyzer,
+  ast::AstKind2,
+  builtins::{
+    constants::{
+      REACT_COMPONENT_OBJECT_ID, REACT_COMPONENT_PROTOTYPE_OBJECT_ID,
+      REACT_PURE_COMPONENT_OBJECT_ID,
+    },
+    prototypes::BuiltinPrototypes,
+  },
+  entity::{ClassEntity, Entity, EntityFactory, EntityTrait, ObjectPropertyValue},
+  init_namespace, init_object,
+};
+
+const LIFECYCLE_METHODS: [&str; 12] = [
+  "componentDidMount",
+  "componentDidUpdate",
+  "componentWillUnmount",
+  "shouldComponentUpdate",
+  "getSnapshotBeforeUpdate",
+  "componentDidCatch",
+  "componentWillMount",
+  "componentWillReceiveProps",
+  "componentWillUpdate",
+  "UNSAFE_componentWillMount",
+  "UNSAFE_componentWillReceiveProps",
+  "UNSAFE_componentWillUpdate",
+];
+
+/// Returns `React.Component` and `React.PureComponent`.
+pub fn create_react_component_classes<'a>(
+  factory: &'a EntityFactory<'a>,
+  prototypes: &'a BuiltinPrototypes<'a>,
+) -> (Entity<'a>, Entity<'a>) {
+  // React itself checks `prototype.isReactComponent` to tell class components apart
+  let prototype =
+    factory.builtin_object(REACT_COMPONENT_PROTOTYPE_OBJECT_ID, &prototypes.object, false);
+  prototype.init_rest(ObjectPropertyValue::Field(factory.immutable_unknown, true));
+  init_namespace!(prototype, {
+    "isReactComponent" => factory.boolean(true),
+  });
+
+  let create_class = |object_id| {
+    let class = factory.builtin_object(object_id, &prototypes.function, false);
+    class.init_rest(ObjectPropertyValue::Field(factory.immutable_unknown, true));
+    init_namespace!(class, {
+      "prototype" => prototype,
+    });
+    class as Entity<'a>
+  };
+
+  (create_class(REACT_COMPONENT_OBJECT_ID), create_class(REACT_PURE_COMPONENT_OBJECT_ID))
+}
+
+impl<'a> Analyzer<'a> {
+  pub fn is_react_class_component(&mut self, class: &'a ClassEntity<'a>) -> bool {
+    let Some(super_class) = class.super_class else {
+      return false;
+    };
+    let prototype = super_class.get_property(
+      self,
+      self.factory.empty_consumable,
+      self.factory.string("prototype"),
+    );
+    let flag = prototype.get_property(
+      self,
+      self.factory.empty_consumable,
+      self.factory.string("isReactComponent"),
+    );
+    flag.test_truthy() == Some(true)
+  }
+
+  /// Renders a class component like React does: the instance is constructed with `props`, then
+  /// `render()` and the lifecycle methods are called.
+  pub fn render_react_class_component(
+    &mut self,
+    class: &'a ClassEntity<'a>,
+    props: Entity<'a>,
+  ) -> Entity<'a> {
+    let dep = self.consumable(AstKind2::Class(class.node));
+    // The class is constructed by React, so it must be kept as a class with its super class
+    self.consume((dep, class.super_class));
+
+    let key_default_props = self.get_react_key("defaultProps");
+    let default_props = class.get_property(self, self.factory.empty_consumable, key_default_props);
+    let props = if default_props.test_nullish() == Some(true) {
+      props
+    } else {
+      let resolved = self.new_empty_object(&self.builtins.prototypes.object, None);
+      resolved.init_spread(self, self.factory.empty_consumable, default_props);
+      resolved.init_spread(self, self.factory.empty_consumable, props);
+      resolved
+    };
+
+    let instance = self.new_empty_object(&self.builtins.prototypes.object, None);
+    init_object!(instance, {
+      "props" => props,
+      "context" => self.factory.immutable_unknown,
+      "setState" => create_react_set_state_impl(self.factory, "React::Component::setState"),
+      "forceUpdate" => create_react_set_state_impl(self.factory, "React::Component::forceUpdate"),
+    });
+    let instance = &*instance;
+
+    let super_class = self.factory.implemented_builtin_fn(
+      "React::Component::constructor",
+      |analyzer, dep, _this, args| {
+        let props = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+        let this = analyzer.get_this();
+        let key_props = analyzer.get_react_key("props");
+        this.set_property(analyzer, analyzer.factory.empty_consumable, key_props, props);
+        // `super()` must be called before accessing `this`, and field initializers may read the
+        // props passed to it before React assigns them again
+        analyzer.consume((dep, args));
+        this
+      },
+    );
+
+    let args =
+      self.factory.arguments(vec![(false, props), (false, self.factory.immutable_unknown)]);
+    let returned = self.init_class_instance(class, dep, instance, Some(super_class), args);
+    self.consume(returned);
+
+    // Props are assigned again after construction, and state changes over time
+    let key_props = self.get_react_key("props");
+    instance.set_property(self, self.factory.empty_consumable, key_props, props);
+    let key_state = self.get_react_key("state");
+    let state = instance.get_property(self, self.factory.empty_consumable, key_state);
+    let state = self.factory.computed_unknown(state);
+    instance.set_property(self, self.factory.empty_consumable, key_state, state);
+
+    let key_get_derived_state_from_props = self.get_react_key("getDerivedStateFromProps");
+    let get_derived_state_from_props =
+      class.get_property(self, self.factory.empty_consumable, key_get_derived_state_from_props);
+    if get_derived_state_from_props.test_nullish() != Some(true) {
+      self.exec_consumed_fn("React_getDerivedStateFromProps", move |analyzer| {
+        get_derived_state_from_props.call(
+          analyzer,
+          analyzer.factory.empty_consumable,
+          class,
+          analyzer.factory.arguments(vec![(false, props), (false, state)]),
+        )
+      });
+    }
+
+    for name in LIFECYCLE_METHODS {
+      let key = self.get_react_key(name);
+      let method = instance.get_property(self, self.factory.empty_consumable, key);
+      if method.test_nullish() != Some(true) {
+        self.exec_consumed_fn("React_lifecycle", move |analyzer| {
+          method.call(
+            analyzer,
+            analyzer.factory.empty_consumable,
+            instance,
+            analyzer.factory.unknown(),
+          )
+        });
+      }
+    }
+
+    let key_render = self.get_react_key("render");
+    let render = instance.get_property(self, self.factory.empty_consumable, key_render);
+    render.call(self, self.factory.empty_consumable, instance, self.factory.empty_arguments)
+  }
+}
+
+/// `setState(updater, callback)` and `forceUpdate(callback)` schedule a re-render.
+#[cfg_attr(not(feature = "flame"), allow(unused_variables))]
+fn create_react_set_state_impl<'a>(
+  factory: &'a EntityFactory<'a>,
+  name: &'static str,
+) -> Entity<'a> {
+  factory.implemented_builtin_fn(name, |analyzer, dep, _this, args| {
+    analyzer.consume((dep, args));
+    analyzer.factory.immutable_unknown
+  })
+}
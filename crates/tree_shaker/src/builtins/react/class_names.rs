@@ -1,33 +1,164 @@
 use crate::{
-  builtins::prototypes::BuiltinPrototypes,
-  entity::{Entity, EntityFactory, TypeofResult},
+  builtins::{
+    constants::{CLSX_NAMESPACE_OBJECT_ID, TAILWIND_MERGE_NAMESPACE_OBJECT_ID},
+    prototypes::BuiltinPrototypes,
+  },
+  entity::{Entity, EntityFactory, LiteralEntity, ObjectPropertyValue, TypeofResult},
+  init_namespace,
 };
 
 pub fn create_class_names_namespace<'a>(
   factory: &'a EntityFactory<'a>,
   _prototypes: &'a BuiltinPrototypes<'a>,
 ) -> Entity<'a> {
-  factory.implemented_builtin_fn("classnames::default", |analyzer, dep, _this, args| {
+  create_class_names_impl(factory, "classnames::default", true)
+}
+
+pub fn create_clsx_namespace<'a>(
+  factory: &'a EntityFactory<'a>,
+  prototypes: &'a BuiltinPrototypes<'a>,
+) -> (Entity<'a>, Entity<'a>) {
+  let namespace = factory.builtin_object(CLSX_NAMESPACE_OBJECT_ID, &prototypes.null, false);
+  namespace.init_rest(ObjectPropertyValue::Field(factory.immutable_unknown, true));
+
+  let clsx = create_class_names_impl(factory, "clsx::default", true);
+
+  init_namespace!(namespace, {
+    "default" => clsx,
+    "clsx" => clsx,
+  });
+
+  (namespace, clsx)
+}
+
+pub fn create_tailwind_merge_namespace<'a>(
+  factory: &'a EntityFactory<'a>,
+  prototypes: &'a BuiltinPrototypes<'a>,
+) -> Entity<'a> {
+  let namespace =
+    factory.builtin_object(TAILWIND_MERGE_NAMESPACE_OBJECT_ID, &prototypes.null, false);
+  namespace.init_rest(ObjectPropertyValue::Field(factory.immutable_unknown, true));
+
+  init_namespace!(namespace, {
+    "twJoin" => create_tw_join_impl(factory),
+    // The result depends on the tailwind config, so it is never folded
+    "twMerge" => create_class_names_impl(factory, "tailwind-merge::twMerge", false),
+  });
+
+  namespace
+}
+
+/// `classnames`, `clsx` and alike: joins the truthy strings and the keys with truthy values of
+/// the objects with spaces. The result is folded if `foldable` and all the inputs are known.
+#[cfg_attr(not(feature = "flame"), allow(unused_variables))]
+fn create_class_names_impl<'a>(
+  factory: &'a EntityFactory<'a>,
+  name: &'static str,
+  foldable: bool,
+) -> Entity<'a> {
+  factory.implemented_builtin_fn(name, move |analyzer, dep, _this, args| {
     let (class_names, rest, iterate_dep) = args.iterate(analyzer, dep);
 
     let mut deps_1 = vec![];
     let mut deps_2 = vec![iterate_dep];
+    let mut folded = (foldable && rest.is_none()).then(Vec::new);
     for class_name in class_names {
-      if TypeofResult::Object.contains(class_name.test_typeof()) {
+      let truthy = class_name.test_truthy();
+      if truthy == Some(false) {
+        // Falsy values, including `null` and `undefined`, are skipped
+        deps_1.push(class_name);
+        continue;
+      }
+
+      if TypeofResult::Object.contains(class_name.test_typeof())
+        && class_name.test_nullish() != Some(false)
+      {
+        // May be `null` or `undefined`, whose properties can't be read
+        deps_1.push(class_name);
+        folded = None;
+      } else if TypeofResult::Object.contains(class_name.test_typeof()) {
+        if folded.is_some() {
+          // Arrays are flattened instead, which is not handled
+          let length = class_name.get_property(analyzer, dep, analyzer.factory.string("length"));
+          if class_name.test_typeof() != TypeofResult::Object
+            || length.test_is_undefined() != Some(true)
+          {
+            folded = None;
+          }
+        }
+
         // This may be an array. However, this makes no difference in this logic.
         let (properties, enumerate_dep) = class_name.enumerate_properties(analyzer, dep);
         deps_2.push(enumerate_dep);
-        for (_, key, value) in properties {
-          if value.test_truthy() != Some(false) {
+        // The properties are not enumerated in insertion order, so the result can only be folded
+        // when at most one of them is included
+        let mut included = false;
+        for (definite, key, value) in properties {
+          // The result depends on the truthiness of every value
+          let truthy = value.test_truthy();
+          if truthy != Some(false) {
             deps_1.push(key);
-            deps_1.push(value);
+          }
+          deps_1.push(value);
+          if let Some(parts) = &mut folded {
+            match (definite, truthy, key.get_literal(analyzer)) {
+              (_, Some(false), _) => {}
+              (true, Some(true), Some(LiteralEntity::String(key, _))) if !included => {
+                included = true;
+                parts.push(key);
+              }
+              _ => folded = None,
+            }
           }
         }
       } else {
         deps_1.push(class_name);
+        if let Some(parts) = &mut folded {
+          match (truthy, class_name.get_literal(analyzer)) {
+            (Some(true), Some(LiteralEntity::String(value, _))) => parts.push(value),
+            _ => folded = None,
+          }
+        }
       }
     }
 
-    analyzer.factory.computed_unknown_string(analyzer.consumable((deps_1, deps_2, rest)))
+    let deps = analyzer.consumable((deps_1, deps_2, rest));
+    if let Some(parts) = folded {
+      let result = analyzer.allocator.alloc(parts.join(" "));
+      analyzer.factory.computed(analyzer.factory.string(result), deps)
+    } else {
+      analyzer.factory.computed_unknown_string(deps)
+    }
+  })
+}
+
+/// `twJoin` joins the truthy strings with spaces. Unlike `clsx`, the keys of objects are not
+/// included.
+#[cfg_attr(not(feature = "flame"), allow(unused_variables))]
+fn create_tw_join_impl<'a>(factory: &'a EntityFactory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("tailwind-merge::twJoin", |analyzer, dep, _this, args| {
+    let (class_names, rest, iterate_dep) = args.iterate(analyzer, dep);
+
+    let mut folded = rest.is_none().then(Vec::new);
+    for class_name in &class_names {
+      match (class_name.test_truthy(), class_name.get_literal(analyzer)) {
+        (Some(false), _) => {}
+        (Some(true), Some(LiteralEntity::String(value, _))) => {
+          if let Some(parts) = &mut folded {
+            parts.push(value);
+          }
+        }
+        // Arrays are flattened, which is not handled
+        _ => folded = None,
+      }
+    }
+
+    let deps = analyzer.consumable((class_names, iterate_dep, rest));
+    if let Some(parts) = folded {
+      let result = analyzer.allocator.alloc(parts.join(" "));
+      analyzer.factory.computed(analyzer.factory.string(result), deps)
+    } else {
+      analyzer.factory.computed_unknown_string(deps)
+    }
   })
 }
@@ -0,0 +1,57 @@
+use crate::entity::{Entity, EntityFactory, TypeofResult};
+
+pub fn create_react_clone_element_impl<'a>(factory: &'a EntityFactory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("React::cloneElement", |analyzer, dep, _this, args| {
+    let (args, children, _) = args.destruct_as_array(analyzer, dep, 2, true);
+    let [element, config] = args[..] else { unreachable!() };
+
+    let tag = element.get_property(analyzer, dep, analyzer.factory.string("type"));
+    let original_props = element.get_property(analyzer, dep, analyzer.factory.string("props"));
+
+    let props = analyzer.new_empty_object(&analyzer.builtins.prototypes.object, None);
+    props.init_spread(analyzer, dep, original_props);
+    match config.test_nullish() {
+      Some(true) => {}
+      Some(false) => props.init_spread(analyzer, dep, config),
+      None => {
+        let config = analyzer.factory.union((
+          config,
+          analyzer.new_empty_object(&analyzer.builtins.prototypes.object, None) as Entity<'a>,
+        ));
+        props.init_spread(analyzer, dep, config);
+      }
+    }
+
+    // Children passed as extra arguments replace the original ones
+    let children = children.unwrap();
+    let has_children = children
+      .get_property(analyzer, dep, analyzer.factory.string("length"))
+      .get_literal(analyzer)
+      .and_then(|length| length.to_number())
+      .map(|length| length != Some(0.0.into()));
+    if has_children != Some(false) {
+      let children = analyzer.factory.computed_unknown(children);
+      if has_children == Some(true) {
+        props.set_property(analyzer, dep, analyzer.factory.string("children"), children);
+      } else {
+        analyzer.push_indeterminate_cf_scope();
+        props.set_property(analyzer, dep, analyzer.factory.string("children"), children);
+        analyzer.pop_cf_scope();
+      }
+    }
+
+    analyzer.factory.react_element(tag, props)
+  })
+}
+
+pub fn create_react_is_valid_element_impl<'a>(factory: &'a EntityFactory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("React::isValidElement", |analyzer, dep, _this, args| {
+    let object = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+    if object.test_typeof().intersects(TypeofResult::Object) && object.test_nullish() != Some(true)
+    {
+      analyzer.factory.computed_unknown_boolean(object)
+    } else {
+      analyzer.factory.computed(analyzer.factory.boolean(false), object)
+    }
+  })
+}
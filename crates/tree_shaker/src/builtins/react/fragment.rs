@@ -0,0 +1,5 @@
+use crate::entity::{Entity, EntityFactory};
+
+pub fn create_react_fragment_impl<'a>(factory: &'a EntityFactory<'a>) -> Entity<'a> {
+  factory.react_fragment()
+}
@@ -0,0 +1,15 @@
+use crate::entity::{Entity, EntityFactory};
+
+use super::jsxs::create_react_jsxs_impl;
+
+pub fn create_react_jsx_dev_impl<'a>(factory: &'a EntityFactory<'a>) -> Entity<'a> {
+  let jsxs = create_react_jsxs_impl(factory);
+  // jsxDEV(type, props, key, isStaticChildren, source, self)
+  factory.implemented_builtin_fn("React::jsxDEV", move |analyzer, dep, this, args| {
+    let dev_args = args.destruct_as_array(analyzer, dep, 6, false).0;
+    let jsx_args = dev_args[..3].iter().map(|arg| (false, *arg)).collect();
+    let element = jsxs.call(analyzer, dep, this, analyzer.factory.arguments(jsx_args));
+    // The extra arguments are only used for debugging, and are kept along with the element
+    analyzer.factory.computed(element, analyzer.consumable(dev_args[3..].to_vec()))
+  })
+}
@@ -0,0 +1,28 @@
+use crate::entity::{Entity, EntityFactory};
+
+pub fn create_react_lazy_impl<'a>(factory: &'a EntityFactory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("React::lazy", |analyzer, dep, _this, args| {
+    let loader = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+
+    let component = analyzer.dynamic_implemented_builtin(
+      "React::LazyComponent",
+      move |analyzer, dep, _this, args| {
+        let props =
+          args.destruct_as_array(analyzer, analyzer.factory.empty_consumable, 1, false).0[0];
+
+        // The loader is only called when the component is rendered
+        let module = loader
+          .call(analyzer, dep, analyzer.factory.undefined, analyzer.factory.empty_arguments)
+          .r#await(analyzer, analyzer.factory.empty_consumable);
+        let component = module.get_property(
+          analyzer,
+          analyzer.factory.empty_consumable,
+          analyzer.factory.string("default"),
+        );
+        component.jsx(analyzer, props)
+      },
+    );
+
+    analyzer.factory.computed(component, loader)
+  })
+}
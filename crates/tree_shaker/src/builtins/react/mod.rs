@@ -1,37 +1,80 @@
+mod children;
+mod class_component;
 mod class_names;
+mod clone_element;
 mod context;
 mod create_element;
 mod dependencies;
 mod forward_ref;
+mod fragment;
 mod jsx;
+mod jsx_dev;
 mod jsxs;
+mod lazy;
 mod memo;
+mod start_transition;
+mod suspense;
+mod use_callback;
+mod use_effect;
 mod use_memo;
+mod use_ref;
+mod use_state;
 
 use super::{
-  constants::{REACT_JSX_RUNTIME_NAMESPACE_OBJECT_ID, REACT_NAMESPACE_OBJECT_ID},
+  constants::{
+    REACT_JSX_DEV_RUNTIME_NAMESPACE_OBJECT_ID, REACT_JSX_RUNTIME_NAMESPACE_OBJECT_ID,
+    REACT_NAMESPACE_OBJECT_ID,
+  },
   prototypes::BuiltinPrototypes,
 };
 use crate::{
+  analyzer::Analyzer,
   entity::{Entity, EntityFactory, ObjectPropertyValue},
   init_namespace,
 };
-pub use class_names::create_class_names_namespace;
+use children::create_react_children_namespace;
+use class_component::create_react_component_classes;
+pub use class_names::{
+  create_class_names_namespace, create_clsx_namespace, create_tailwind_merge_namespace,
+};
+pub use clone_element::{create_react_clone_element_impl, create_react_is_valid_element_impl};
 use context::{create_react_create_context_impl, create_react_use_context_impl, ReactContexts};
-use create_element::create_react_create_element_impl;
+pub use create_element::create_react_create_element_impl;
 use dependencies::ReactDependencies;
 use forward_ref::create_react_forward_ref_impl;
+pub use fragment::create_react_fragment_impl;
 use jsx::create_react_jsx_impl;
+use jsx_dev::create_react_jsx_dev_impl;
 use jsxs::create_react_jsxs_impl;
+use lazy::create_react_lazy_impl;
 use memo::create_react_memo_impl;
+use rustc_hash::FxHashMap;
+use start_transition::create_react_start_transition_impl;
+use suspense::create_react_suspense_impl;
+use use_callback::create_react_use_callback_impl;
+use use_effect::create_react_use_effect_impl;
 use use_memo::{create_react_use_memo_impl, ReactUseMemos};
+use use_ref::create_react_use_ref_impl;
+use use_state::{create_react_use_reducer_impl, create_react_use_state_impl};
 
 #[derive(Debug, Default)]
 pub struct AnalyzerDataForReact<'a> {
   pub contexts: ReactContexts<'a>,
   pub memos: ReactUseMemos<'a>,
   pub dependencies: ReactDependencies<'a>,
-  pub key_children: Option<Entity<'a>>,
+  /// Property keys read by React itself, which must not be mangled
+  pub keys: FxHashMap<&'static str, Entity<'a>>,
+}
+
+impl<'a> Analyzer<'a> {
+  pub fn get_react_key(&mut self, name: &'static str) -> Entity<'a> {
+    if let Some(key) = self.builtins.react_data.keys.get(name) {
+      return *key;
+    }
+    let key = self.factory.mangable_string(name, self.mangler.new_constant_atom(name));
+    self.builtins.react_data.keys.insert(name, key);
+    key
+  }
 }
 
 pub fn create_react_namespace<'a>(
@@ -41,13 +84,30 @@ pub fn create_react_namespace<'a>(
   let namespace = factory.builtin_object(REACT_NAMESPACE_OBJECT_ID, &prototypes.null, false);
   namespace.init_rest(ObjectPropertyValue::Field(factory.immutable_unknown, true));
 
+  let (component, pure_component) = create_react_component_classes(factory, prototypes);
+
   init_namespace!(namespace, {
+    "Component" => component,
+    "PureComponent" => pure_component,
     "forwardRef" => create_react_forward_ref_impl(factory),
     "memo" => create_react_memo_impl(factory),
     "createElement" => create_react_create_element_impl(factory),
     "createContext" => create_react_create_context_impl(factory),
     "useContext" => create_react_use_context_impl(factory),
     "useMemo" => create_react_use_memo_impl(factory),
+    "useCallback" => create_react_use_callback_impl(factory),
+    "useState" => create_react_use_state_impl(factory),
+    "useReducer" => create_react_use_reducer_impl(factory),
+    "useEffect" => create_react_use_effect_impl(factory, "React::useEffect"),
+    "useLayoutEffect" => create_react_use_effect_impl(factory, "React::useLayoutEffect"),
+    "useRef" => create_react_use_ref_impl(factory),
+    "Fragment" => create_react_fragment_impl(factory),
+    "Children" => create_react_children_namespace(factory, prototypes),
+    "cloneElement" => create_react_clone_element_impl(factory),
+    "isValidElement" => create_react_is_valid_element_impl(factory),
+    "lazy" => create_react_lazy_impl(factory),
+    "Suspense" => create_react_suspense_impl(factory),
+    "startTransition" => create_react_start_transition_impl(factory),
   });
 
   namespace
@@ -64,6 +124,23 @@ pub fn create_react_jsx_runtime_namespace<'a>(
   init_namespace!(object, {
     "jsx" => create_react_jsx_impl(factory),
     "jsxs" => create_react_jsxs_impl(factory),
+    "Fragment" => create_react_fragment_impl(factory),
+  });
+
+  object
+}
+
+pub fn create_react_jsx_dev_runtime_namespace<'a>(
+  factory: &'a EntityFactory<'a>,
+  prototypes: &'a BuiltinPrototypes<'a>,
+) -> Entity<'a> {
+  let object =
+    factory.builtin_object(REACT_JSX_DEV_RUNTIME_NAMESPACE_OBJECT_ID, &prototypes.null, false);
+  object.init_rest(ObjectPropertyValue::Field(factory.immutable_unknown, true));
+
+  init_namespace!(object, {
+    "jsxDEV" => create_react_jsx_dev_impl(factory),
+    "Fragment" => create_react_fragment_impl(factory),
   });
 
   object
@@ -0,0 +1,12 @@
+use crate::entity::{Entity, EntityFactory};
+
+pub fn create_react_start_transition_impl<'a>(factory: &'a EntityFactory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("React::startTransition", |analyzer, dep, _this, args| {
+    let scope = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+
+    // The scope is called synchronously, and only its effects matter
+    scope.call(analyzer, dep, analyzer.factory.undefined, analyzer.factory.empty_arguments);
+
+    analyzer.factory.undefined
+  })
+}
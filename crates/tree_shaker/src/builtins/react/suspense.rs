@@ -0,0 +1,14 @@
+use crate::entity::{Entity, EntityFactory};
+
+pub fn create_react_suspense_impl<'a>(factory: &'a EntityFactory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("React::Suspense", |analyzer, dep, _this, args| {
+    let props = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+
+    // Both the children and the fallback may be rendered
+    let children = props.get_property(analyzer, dep, analyzer.factory.string("children"));
+    let fallback = props.get_property(analyzer, dep, analyzer.factory.string("fallback"));
+    analyzer.consume((children, fallback));
+
+    analyzer.factory.immutable_unknown
+  })
+}
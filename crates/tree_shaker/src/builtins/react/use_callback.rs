@@ -0,0 +1,21 @@
+use super::dependencies::check_dependencies;
+use crate::entity::{Entity, EntityFactory};
+
+pub fn create_react_use_callback_impl<'a>(factory: &'a EntityFactory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("React::useCallback", |analyzer, dep, _this, args| {
+    let [callback, dependencies] = args.destruct_as_array(analyzer, dep, 2, false).0[..] else {
+      unreachable!()
+    };
+
+    let (changed, dep) = check_dependencies(analyzer, dep, dependencies);
+
+    // Shares the storage with `useMemo`, because `useCallback(fn, deps)` is `useMemo(() => fn, deps)`
+    let span = analyzer.current_span();
+    if changed {
+      analyzer.builtins.react_data.memos.insert(span, callback);
+      analyzer.factory.computed(callback, dep)
+    } else {
+      analyzer.factory.computed(analyzer.builtins.react_data.memos[&span], dep)
+    }
+  })
+}
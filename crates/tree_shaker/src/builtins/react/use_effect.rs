@@ -0,0 +1,38 @@
+use super::dependencies::check_dependencies;
+use crate::entity::{Entity, EntityFactory};
+
+pub fn create_react_use_effect_impl<'a>(
+  factory: &'a EntityFactory<'a>,
+  name: &'static str,
+) -> Entity<'a> {
+  factory.implemented_builtin_fn(name, |analyzer, dep, _this, args| {
+    let [effect, dependencies] = args.destruct_as_array(analyzer, dep, 2, false).0[..] else {
+      unreachable!()
+    };
+
+    let (changed, dep) = if dependencies.test_is_undefined() == Some(true) {
+      // Runs after every render
+      (true, dep)
+    } else {
+      check_dependencies(analyzer, dep, dependencies)
+    };
+
+    if changed {
+      analyzer.exec_consumed_fn("React_effect", move |analyzer| {
+        let cleanup =
+          effect.call(analyzer, dep, analyzer.factory.undefined, analyzer.factory.empty_arguments);
+        if cleanup.test_nullish() != Some(true) {
+          cleanup.call(
+            analyzer,
+            analyzer.factory.empty_consumable,
+            analyzer.factory.undefined,
+            analyzer.factory.empty_arguments,
+          );
+        }
+        analyzer.factory.undefined
+      });
+    }
+
+    analyzer.factory.undefined
+  })
+}
@@ -0,0 +1,17 @@
+use crate::{
+  entity::{Entity, EntityFactory},
+  init_object,
+};
+
+pub fn create_react_use_ref_impl<'a>(factory: &'a EntityFactory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("React::useRef", |analyzer, dep, _this, args| {
+    let initial = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+
+    let r#ref = analyzer.new_empty_object(&analyzer.builtins.prototypes.object, None);
+    init_object!(r#ref, {
+      "current" => initial,
+    });
+
+    analyzer.factory.computed(r#ref, dep)
+  })
+}
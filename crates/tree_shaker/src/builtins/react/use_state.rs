@@ -0,0 +1,92 @@
+use crate::{
+  analyzer::Analyzer,
+  consumable::Consumable,
+  entity::{Entity, EntityFactory, TypeofResult},
+};
+
+pub fn create_react_use_state_impl<'a>(factory: &'a EntityFactory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("React::useState", |analyzer, dep, _this, args| {
+    let initial = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+    let initial = resolve_lazy_initial_value(analyzer, initial, None);
+
+    let hook_dep = dep;
+    let setter = analyzer.dynamic_implemented_builtin(
+      "React::useState::setState",
+      move |analyzer, dep, _, args| {
+        analyzer.consume((hook_dep, dep, args));
+        analyzer.factory.immutable_unknown
+      },
+    );
+
+    create_state_pair(analyzer, dep, analyzer.factory.computed_unknown(initial), setter)
+  })
+}
+
+pub fn create_react_use_reducer_impl<'a>(factory: &'a EntityFactory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("React::useReducer", |analyzer, dep, _this, args| {
+    let [reducer, initial_arg, init] = args.destruct_as_array(analyzer, dep, 3, false).0[..] else {
+      unreachable!()
+    };
+    let initial = resolve_lazy_initial_value(analyzer, init, Some(initial_arg));
+
+    // The reducer is only called by React when the dispatcher is called
+    let hook_dep = dep;
+    let dispatch = analyzer.dynamic_implemented_builtin(
+      "React::useReducer::dispatch",
+      move |analyzer, dep, _, args| {
+        analyzer.consume((hook_dep, dep, args, reducer));
+        analyzer.factory.immutable_unknown
+      },
+    );
+
+    create_state_pair(
+      analyzer,
+      dep,
+      analyzer.factory.computed_unknown((initial, reducer)),
+      dispatch,
+    )
+  })
+}
+
+/// For `useState`, `init` is the initial value or its initializer.
+/// For `useReducer`, `init` is the optional initializer of `initial_arg`.
+fn resolve_lazy_initial_value<'a>(
+  analyzer: &mut Analyzer<'a>,
+  init: Entity<'a>,
+  initial_arg: Option<Entity<'a>>,
+) -> Entity<'a> {
+  let args = match initial_arg {
+    Some(initial_arg) => {
+      if init.test_is_undefined() == Some(true) {
+        return initial_arg;
+      }
+      analyzer.factory.arguments(vec![(false, initial_arg)])
+    }
+    None => analyzer.factory.empty_arguments,
+  };
+
+  let typeof_init = init.test_typeof();
+  if typeof_init == TypeofResult::Function || initial_arg.is_some() {
+    init.call(analyzer, analyzer.factory.empty_consumable, analyzer.factory.undefined, args)
+  } else if typeof_init.contains(TypeofResult::Function) {
+    // May be called by React
+    analyzer.consume(init);
+    analyzer.factory.unknown()
+  } else {
+    init
+  }
+}
+
+fn create_state_pair<'a>(
+  analyzer: &mut Analyzer<'a>,
+  dep: Consumable<'a>,
+  state: Entity<'a>,
+  setter: Entity<'a>,
+) -> Entity<'a> {
+  let array = analyzer.new_empty_array();
+  array.push_element(state);
+  array.push_element(setter);
+  // The setter updates the state based on the initial one, so the initial state is needed as long
+  // as the pair is used
+  analyzer.factory.computed(array, (dep, state))
+}
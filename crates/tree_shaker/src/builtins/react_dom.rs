@@ -0,0 +1,140 @@
+use super::{
+  constants::{
+    REACT_DOM_CLIENT_NAMESPACE_OBJECT_ID, REACT_DOM_NAMESPACE_OBJECT_ID, REACT_DOM_ROOT_OBJECT_ID,
+    REACT_DOM_SERVER_NAMESPACE_OBJECT_ID,
+  },
+  prototypes::BuiltinPrototypes,
+};
+use crate::{
+  entity::{Entity, EntityFactory, ObjectPropertyValue},
+  init_namespace,
+};
+
+pub fn create_react_dom_namespace<'a>(
+  factory: &'a EntityFactory<'a>,
+  prototypes: &'a BuiltinPrototypes<'a>,
+) -> Entity<'a> {
+  let namespace = factory.builtin_object(REACT_DOM_NAMESPACE_OBJECT_ID, &prototypes.null, false);
+  namespace.init_rest(ObjectPropertyValue::Field(factory.immutable_unknown, true));
+
+  let render = create_react_dom_mount_impl(factory, "ReactDOM::render");
+
+  init_namespace!(namespace, {
+    "render" => render,
+    "hydrate" => render,
+    "unmountComponentAtNode" => create_react_dom_mount_impl(factory, "ReactDOM::unmountComponentAtNode"),
+    "createPortal" => create_react_dom_create_portal_impl(factory),
+    "flushSync" => create_react_dom_flush_sync_impl(factory),
+  });
+
+  namespace
+}
+
+pub fn create_react_dom_client_namespace<'a>(
+  factory: &'a EntityFactory<'a>,
+  prototypes: &'a BuiltinPrototypes<'a>,
+) -> Entity<'a> {
+  let namespace =
+    factory.builtin_object(REACT_DOM_CLIENT_NAMESPACE_OBJECT_ID, &prototypes.null, false);
+  namespace.init_rest(ObjectPropertyValue::Field(factory.immutable_unknown, true));
+
+  let root = create_react_dom_root(factory, prototypes);
+
+  init_namespace!(namespace, {
+    "createRoot" => create_react_dom_create_root_impl(factory, "ReactDOM::createRoot", root),
+    "hydrateRoot" => create_react_dom_create_root_impl(factory, "ReactDOM::hydrateRoot", root),
+  });
+
+  namespace
+}
+
+pub fn create_react_dom_server_namespace<'a>(
+  factory: &'a EntityFactory<'a>,
+  prototypes: &'a BuiltinPrototypes<'a>,
+) -> Entity<'a> {
+  let namespace =
+    factory.builtin_object(REACT_DOM_SERVER_NAMESPACE_OBJECT_ID, &prototypes.null, false);
+  namespace.init_rest(ObjectPropertyValue::Field(factory.immutable_unknown, true));
+
+  let render_to_string = create_react_dom_render_to_string_impl(factory);
+  let render_to_stream = create_react_dom_render_to_stream_impl(factory);
+
+  init_namespace!(namespace, {
+    "renderToString" => render_to_string,
+    "renderToStaticMarkup" => render_to_string,
+    "renderToPipeableStream" => render_to_stream,
+    "renderToReadableStream" => render_to_stream,
+    "renderToStaticNodeStream" => render_to_stream,
+  });
+
+  namespace
+}
+
+/// The object returned by `createRoot` and `hydrateRoot`. It holds no state, so it is shared.
+fn create_react_dom_root<'a>(
+  factory: &'a EntityFactory<'a>,
+  prototypes: &'a BuiltinPrototypes<'a>,
+) -> Entity<'a> {
+  let root = factory.builtin_object(REACT_DOM_ROOT_OBJECT_ID, &prototypes.object, false);
+  root.init_rest(ObjectPropertyValue::Field(factory.immutable_unknown, true));
+
+  init_namespace!(root, {
+    "render" => create_react_dom_mount_impl(factory, "ReactDOM::Root::render"),
+    "unmount" => create_react_dom_mount_impl(factory, "ReactDOM::Root::unmount"),
+  });
+
+  root
+}
+
+/// Mounting to or unmounting from the DOM is the side effect. Consuming the element renders it.
+#[cfg_attr(not(feature = "flame"), allow(unused_variables))]
+fn create_react_dom_mount_impl<'a>(
+  factory: &'a EntityFactory<'a>,
+  name: &'static str,
+) -> Entity<'a> {
+  factory.implemented_builtin_fn(name, |analyzer, dep, _this, args| {
+    analyzer.consume((dep, args));
+    analyzer.factory.unknown()
+  })
+}
+
+#[cfg_attr(not(feature = "flame"), allow(unused_variables))]
+fn create_react_dom_create_root_impl<'a>(
+  factory: &'a EntityFactory<'a>,
+  name: &'static str,
+  root: Entity<'a>,
+) -> Entity<'a> {
+  factory.implemented_builtin_fn(name, move |analyzer, dep, _this, args| {
+    analyzer.consume(args);
+    analyzer.factory.computed(root, dep)
+  })
+}
+
+fn create_react_dom_create_portal_impl<'a>(factory: &'a EntityFactory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("ReactDOM::createPortal", |analyzer, dep, _this, args| {
+    // The children are rendered when the portal is
+    analyzer.factory.computed_unknown((dep, args))
+  })
+}
+
+fn create_react_dom_flush_sync_impl<'a>(factory: &'a EntityFactory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("ReactDOM::flushSync", |analyzer, dep, _this, args| {
+    let callback = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+    callback.call(analyzer, dep, analyzer.factory.undefined, analyzer.factory.empty_arguments)
+  })
+}
+
+fn create_react_dom_render_to_string_impl<'a>(factory: &'a EntityFactory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("ReactDOMServer::renderToString", |analyzer, dep, _this, args| {
+    // The components are called synchronously, so the element is rendered eagerly
+    analyzer.consume((dep, args));
+    analyzer.factory.unknown_string
+  })
+}
+
+fn create_react_dom_render_to_stream_impl<'a>(factory: &'a EntityFactory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("ReactDOMServer::renderToStream", |analyzer, dep, _this, args| {
+    analyzer.consume((dep, args));
+    analyzer.factory.unknown()
+  })
+}
@@ -0,0 +1,85 @@
+use super::{
+  constants::{SOLID_H_NAMESPACE_OBJECT_ID, SOLID_WEB_NAMESPACE_OBJECT_ID},
+  prototypes::BuiltinPrototypes,
+};
+use crate::{
+  entity::{Entity, EntityFactory, ObjectPropertyValue, TypeofResult},
+  init_namespace,
+};
+
+pub fn create_solid_web_namespace<'a>(
+  factory: &'a EntityFactory<'a>,
+  prototypes: &'a BuiltinPrototypes<'a>,
+) -> Entity<'a> {
+  let namespace = factory.builtin_object(SOLID_WEB_NAMESPACE_OBJECT_ID, &prototypes.null, false);
+  namespace.init_rest(ObjectPropertyValue::Field(factory.immutable_unknown, true));
+
+  init_namespace!(namespace, {
+    "createComponent" => create_solid_create_component_impl(factory),
+  });
+
+  namespace
+}
+
+/// `solid-js/h`, the hyperscript runtime used by the classic JSX transform.
+pub fn create_solid_h_namespace<'a>(
+  factory: &'a EntityFactory<'a>,
+  prototypes: &'a BuiltinPrototypes<'a>,
+) -> (Entity<'a>, Entity<'a>) {
+  let namespace = factory.builtin_object(SOLID_H_NAMESPACE_OBJECT_ID, &prototypes.null, false);
+  namespace.init_rest(ObjectPropertyValue::Field(factory.immutable_unknown, true));
+
+  let h = create_solid_h_impl(factory);
+
+  init_namespace!(namespace, {
+    "default" => h,
+  });
+
+  (namespace, h)
+}
+
+/// `h(Comp, props)` creates the component at once, like `createComponent`. Intrinsic elements
+/// and children are DOM nodes, which are not tracked.
+fn create_solid_h_impl<'a>(factory: &'a EntityFactory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("Solid::h", |analyzer, dep, _this, args| {
+    let (elements, rest, dep) = args.iterate(analyzer, dep);
+    if elements.len() > 2 || rest.is_some() {
+      analyzer.consume((dep, elements, rest));
+      return analyzer.factory.unknown();
+    }
+    let tag = elements.first().copied().unwrap_or(analyzer.factory.undefined);
+    let props = elements.get(1).copied().unwrap_or(analyzer.factory.undefined);
+    if tag.test_typeof() != TypeofResult::Function {
+      analyzer.consume((dep, tag, props));
+      return analyzer.factory.unknown();
+    }
+    let props = match props.test_nullish() {
+      Some(true) => analyzer.new_empty_object(&analyzer.builtins.prototypes.object, None),
+      _ => props,
+    };
+    tag.call(
+      analyzer,
+      dep,
+      analyzer.factory.undefined,
+      analyzer.factory.arguments(vec![(false, props)]),
+    )
+  })
+}
+
+/// Solid components run once, at the time they are created.
+fn create_solid_create_component_impl<'a>(factory: &'a EntityFactory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("Solid::createComponent", |analyzer, dep, _this, args| {
+    let args = args.destruct_as_array(analyzer, dep, 2, false).0;
+    let [component, props] = args[..] else { unreachable!() };
+    let props = match props.test_nullish() {
+      Some(true) => analyzer.new_empty_object(&analyzer.builtins.prototypes.object, None),
+      _ => props,
+    };
+    component.call(
+      analyzer,
+      dep,
+      analyzer.factory.undefined,
+      analyzer.factory.arguments(vec![(false, props)]),
+    )
+  })
+}
@@ -0,0 +1,19 @@
+use crate::entity::{Entity, EntityFactory};
+
+pub fn create_vue_define_component_impl<'a>(factory: &'a EntityFactory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("Vue::defineComponent", |analyzer, dep, _this, args| {
+    let options = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+
+    analyzer.factory.computed(options, dep)
+  })
+}
+
+/// `withCtx(fn, ctx)` binds a slot function to the rendering instance.
+pub fn create_vue_with_ctx_impl<'a>(factory: &'a EntityFactory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("Vue::withCtx", |analyzer, dep, _this, args| {
+    let args = args.destruct_as_array(analyzer, dep, 2, false).0;
+    let [slot, ctx] = args[..] else { unreachable!() };
+
+    analyzer.factory.computed(slot, ctx)
+  })
+}
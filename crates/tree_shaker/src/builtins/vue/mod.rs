@@ -0,0 +1,40 @@
+mod define_component;
+mod reactivity;
+mod vnode;
+
+use super::{
+  constants::VUE_NAMESPACE_OBJECT_ID, prototypes::BuiltinPrototypes,
+  react::create_react_fragment_impl,
+};
+use crate::{
+  entity::{Entity, EntityFactory, ObjectPropertyValue},
+  init_namespace,
+};
+use define_component::{create_vue_define_component_impl, create_vue_with_ctx_impl};
+use reactivity::{create_vue_computed_impl, create_vue_ref_impl};
+use vnode::{create_vue_open_block_impl, create_vue_vnode_impl};
+
+pub fn create_vue_namespace<'a>(
+  factory: &'a EntityFactory<'a>,
+  prototypes: &'a BuiltinPrototypes<'a>,
+) -> Entity<'a> {
+  let namespace = factory.builtin_object(VUE_NAMESPACE_OBJECT_ID, &prototypes.null, false);
+  namespace.init_rest(ObjectPropertyValue::Field(factory.immutable_unknown, true));
+
+  init_namespace!(namespace, {
+    "h" => create_vue_vnode_impl(factory, "Vue::h"),
+    "createVNode" => create_vue_vnode_impl(factory, "Vue::createVNode"),
+    "createElementVNode" => create_vue_vnode_impl(factory, "Vue::createElementVNode"),
+    "createBlock" => create_vue_vnode_impl(factory, "Vue::createBlock"),
+    "createElementBlock" => create_vue_vnode_impl(factory, "Vue::createElementBlock"),
+    "openBlock" => create_vue_open_block_impl(factory),
+    "Fragment" => create_react_fragment_impl(factory),
+    "defineComponent" => create_vue_define_component_impl(factory),
+    "withCtx" => create_vue_with_ctx_impl(factory),
+    "ref" => create_vue_ref_impl(factory, "Vue::ref"),
+    "shallowRef" => create_vue_ref_impl(factory, "Vue::shallowRef"),
+    "computed" => create_vue_computed_impl(factory),
+  });
+
+  namespace
+}
@@ -0,0 +1,44 @@
+use crate::{
+  entity::{Entity, EntityFactory, TypeofResult},
+  init_object,
+};
+use oxc::ast::ast::PropertyKind;
+
+#[cfg_attr(not(feature = "flame"), allow(unused_variables))]
+pub fn create_vue_ref_impl<'a>(factory: &'a EntityFactory<'a>, name: &'static str) -> Entity<'a> {
+  factory.implemented_builtin_fn(name, |analyzer, dep, _this, args| {
+    let initial = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+
+    let r#ref = analyzer.new_empty_object(&analyzer.builtins.prototypes.object, None);
+    init_object!(r#ref, {
+      "value" => initial,
+    });
+
+    analyzer.factory.computed(r#ref, dep)
+  })
+}
+
+pub fn create_vue_computed_impl<'a>(factory: &'a EntityFactory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("Vue::computed", |analyzer, dep, _this, args| {
+    let options = args.destruct_as_array(analyzer, dep, 1, false).0[0];
+
+    let (getter, setter) = if TypeofResult::Function.contains(options.test_typeof()) {
+      (options, None)
+    } else {
+      // Writable computed: `computed({ get, set })`
+      let getter = options.get_property(analyzer, dep, analyzer.factory.string("get"));
+      let setter = options.get_property(analyzer, dep, analyzer.factory.string("set"));
+      (getter, Some(setter))
+    };
+
+    let r#ref = analyzer.new_empty_object(&analyzer.builtins.prototypes.object, None);
+    let key = analyzer.factory.string("value");
+    r#ref.init_property(analyzer, PropertyKind::Get, key, getter, true);
+    if let Some(setter) = setter {
+      r#ref.init_property(analyzer, PropertyKind::Set, key, setter, true);
+    }
+
+    // Vue may call the getter whenever the ref is used
+    analyzer.factory.computed(r#ref, (dep, getter))
+  })
+}
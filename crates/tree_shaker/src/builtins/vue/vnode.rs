@@ -0,0 +1,44 @@
+use crate::entity::{Entity, EntityFactory};
+
+/// `h(type, props, children)`, `createVNode(type, props, children, patchFlag, dynamicProps)` and
+/// their compiled variants. Unlike `React.createElement`, children are passed as a single argument.
+#[cfg_attr(not(feature = "flame"), allow(unused_variables))]
+pub fn create_vue_vnode_impl<'a>(factory: &'a EntityFactory<'a>, name: &'static str) -> Entity<'a> {
+  factory.implemented_builtin_fn(name, |analyzer, dep, _this, args| {
+    let (args, rest, _) = args.destruct_as_array(analyzer, dep, 3, true);
+    let [tag, props, children] = args[..] else { unreachable!() };
+    let props = match props.test_nullish() {
+      Some(true) => {
+        // The renderer stores `props` as is, so `null` can't be replaced with other values
+        analyzer.consume(props);
+        analyzer.new_empty_object(&analyzer.builtins.prototypes.object, None)
+      }
+      Some(false) => props,
+      None => analyzer.factory.union((
+        props,
+        analyzer.new_empty_object(&analyzer.builtins.prototypes.object, None) as Entity<'a>,
+      )),
+    };
+
+    if children.test_is_undefined() != Some(true) {
+      props.set_property(
+        analyzer,
+        analyzer.factory.empty_consumable,
+        analyzer.factory.string("children"),
+        children,
+      );
+    }
+
+    // Compiler hints like `patchFlag` only matter if the vnode is rendered
+    analyzer.factory.computed(analyzer.factory.react_element(tag, props), rest.unwrap())
+  })
+}
+
+/// `openBlock()` pushes to the block stack of the renderer, which is popped by the next
+/// `createBlock()`, so it is never removable on its own.
+pub fn create_vue_open_block_impl<'a>(factory: &'a EntityFactory<'a>) -> Entity<'a> {
+  factory.implemented_builtin_fn("Vue::openBlock", |analyzer, dep, _this, args| {
+    analyzer.consume((dep, args));
+    analyzer.factory.undefined
+  })
+}
@@ -1,16 +1,38 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+use crate::utils::parse_define_value;
+use regex::Regex;
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+use std::{fs, path::Path};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum TreeShakeJsxPreset {
   None,
   React,
+  Preact,
+  Vue,
+  Solid,
 }
 
 impl TreeShakeJsxPreset {
   pub fn is_enabled(&self) -> bool {
     *self != Self::None
   }
+
+  /// The factory and fragment bindings used by the classic runtime, which must be kept alive as
+  /// long as a JSX element is kept. React is assumed to use the automatic runtime, and Solid's
+  /// hyperscript runtime (`solid-js/h`) has no fragment binding.
+  pub fn classic_runtime(&self) -> Option<(&'static str, Option<&'static str>)> {
+    match self {
+      Self::Preact | Self::Vue => Some(("h", Some("Fragment"))),
+      Self::Solid => Some(("h", None)),
+      Self::None | Self::React => None,
+    }
+  }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(try_from = "TreeShakeConfigFile")]
 pub struct TreeShakeConfig {
   pub enabled: bool,
   pub jsx: TreeShakeJsxPreset,
@@ -18,6 +40,12 @@ pub struct TreeShakeConfig {
   pub max_recursion_depth: usize,
 
   pub mangling: bool,
+  /// Property names which are never mangled, like keys of API payloads.
+  pub mangle_reserved: Vec<String>,
+  /// If set, only property names matching this regex are mangled.
+  pub mangle_include: Option<Regex>,
+  /// Property names matching this regex are never mangled.
+  pub mangle_exclude: Option<Regex>,
   pub unknown_global_side_effects: bool,
   pub preserve_function_name: bool,
   pub preserve_function_length: bool,
@@ -28,6 +56,16 @@ pub struct TreeShakeConfig {
   pub min_simple_number_value: i64,
   pub max_simple_number_value: i64,
   pub max_simple_string_length: usize,
+
+  /// Calls to these paths (like `console.log` or `myLib.track`) on globals and imports are pure,
+  /// as well as calls to their members.
+  pub manual_pure_functions: Vec<String>,
+  /// Global identifiers and member chains (like `process.env.NODE_ENV`) replaced with literals
+  /// (like `"production"`, `false` or `0`).
+  pub define: FxHashMap<String, String>,
+  /// Properties of `import.meta`, keyed by their path relative to it (like `env.MODE` or `hot`).
+  /// The value is a literal as in `define`, or `None` if the property is known to be absent.
+  pub import_meta: FxHashMap<String, Option<String>>,
 }
 
 impl Default for TreeShakeConfig {
@@ -45,6 +83,9 @@ impl TreeShakeConfig {
       max_recursion_depth: 2,
 
       mangling: false,
+      mangle_reserved: vec![],
+      mangle_include: None,
+      mangle_exclude: None,
       unknown_global_side_effects: true,
       preserve_function_name: true,
       preserve_function_length: true,
@@ -55,6 +96,10 @@ impl TreeShakeConfig {
       min_simple_number_value: -999,
       max_simple_number_value: 999,
       max_simple_string_length: 12,
+
+      manual_pure_functions: vec![],
+      define: FxHashMap::default(),
+      import_meta: FxHashMap::default(),
     }
   }
 
@@ -86,16 +131,92 @@ impl TreeShakeConfig {
     Self { enabled: false, ..Default::default() }
   }
 
+  pub fn preset(name: &str) -> Option<Self> {
+    match name {
+      "safest" => Some(Self::safest()),
+      "recommended" => Some(Self::recommended()),
+      "smallest" => Some(Self::smallest()),
+      "disabled" => Some(Self::disabled()),
+      _ => None,
+    }
+  }
+
+  /// Loads a JSON or TOML config file, depending on its extension. See [`Self::with_json`].
+  pub fn with_file(self, path: impl AsRef<Path>) -> Result<Self, String> {
+    let path = path.as_ref();
+    let source =
+      fs::read_to_string(path).map_err(|e| format!("Couldn't read {}: {}", path.display(), e))?;
+    match path.extension().and_then(|ext| ext.to_str()) {
+      Some("json") => self.with_json(&source),
+      Some("toml") => self.with_toml(&source),
+      _ => Err(format!("Unsupported config file: {}", path.display())),
+    }
+  }
+
+  /// The fields in the config override the preset specified by its `preset` field, or `self` if
+  /// there is none.
+  pub fn with_json(self, source: &str) -> Result<Self, String> {
+    let file: TreeShakeConfigFile =
+      serde_json::from_str(source).map_err(|e| format!("Invalid config: {}", e))?;
+    file.apply(self)
+  }
+
+  /// Same as [`Self::with_json`], but in TOML.
+  pub fn with_toml(self, source: &str) -> Result<Self, String> {
+    let file: TreeShakeConfigFile =
+      toml::from_str(source).map_err(|e| format!("Invalid config: {}", e))?;
+    file.apply(self)
+  }
+
+  /// Checks the values which are only parsed during the analysis, so that an invalid config is
+  /// reported when it is loaded.
+  pub fn validate(&self) -> Result<(), String> {
+    for (path, value) in &self.define {
+      if parse_define_value(value).is_none() {
+        return Err(format!("Invalid define value for {}: {}", path, value));
+      }
+    }
+    for (path, value) in &self.import_meta {
+      if let Some(value) = value {
+        if parse_define_value(value).is_none() {
+          return Err(format!("Invalid import.meta value for {}: {}", path, value));
+        }
+      }
+      let mut parent = path.as_str();
+      while let Some((grandparent, _)) = parent.rsplit_once('.') {
+        if self.import_meta.contains_key(grandparent) {
+          return Err(format!(
+            "Conflicting import.meta config: {} is both a value and an object",
+            grandparent
+          ));
+        }
+        parent = grandparent;
+      }
+    }
+    Ok(())
+  }
+
   pub fn with_react_jsx(mut self, yes: bool) -> Self {
     self.jsx = if yes { TreeShakeJsxPreset::React } else { TreeShakeJsxPreset::None };
     self
   }
 
+  pub fn with_jsx(mut self, preset: TreeShakeJsxPreset) -> Self {
+    self.jsx = preset;
+    self
+  }
+
+  /// Inlines literals of any size, or restores the default limits if `yes` is `false`.
   pub fn with_always_inline_literal(mut self, yes: bool) -> Self {
     if yes {
       self.min_simple_number_value = i64::MIN;
       self.max_simple_number_value = i64::MAX;
       self.max_simple_string_length = usize::MAX;
+    } else {
+      let default = Self::default();
+      self.min_simple_number_value = default.min_simple_number_value;
+      self.max_simple_number_value = default.max_simple_number_value;
+      self.max_simple_string_length = default.max_simple_string_length;
     }
     self
   }
@@ -105,8 +226,129 @@ impl TreeShakeConfig {
     self
   }
 
+  pub fn with_mangle_reserved(mut self, names: Vec<String>) -> Self {
+    self.mangle_reserved = names;
+    self
+  }
+
+  pub fn with_mangle_include(mut self, regex: Option<Regex>) -> Self {
+    self.mangle_include = regex;
+    self
+  }
+
+  pub fn with_mangle_exclude(mut self, regex: Option<Regex>) -> Self {
+    self.mangle_exclude = regex;
+    self
+  }
+
+  pub fn with_manual_pure_functions(mut self, paths: Vec<String>) -> Self {
+    self.manual_pure_functions = paths;
+    self
+  }
+
+  pub fn with_define(mut self, define: FxHashMap<String, String>) -> Self {
+    self.define = define;
+    self
+  }
+
+  pub fn with_import_meta(mut self, import_meta: FxHashMap<String, Option<String>>) -> Self {
+    self.import_meta = import_meta;
+    self
+  }
+
   pub fn with_max_recursion_depth(mut self, depth: usize) -> Self {
     self.max_recursion_depth = depth;
     self
   }
 }
+
+/// The serialized form of [`TreeShakeConfig`], where every field is optional.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct TreeShakeConfigFile {
+  preset: Option<String>,
+  jsx: Option<TreeShakeJsxPreset>,
+  always_inline_literal: Option<bool>,
+
+  max_recursion_depth: Option<usize>,
+
+  mangling: Option<bool>,
+  mangle_reserved: Option<Vec<String>>,
+  mangle_include: Option<String>,
+  mangle_exclude: Option<String>,
+  unknown_global_side_effects: Option<bool>,
+  preserve_function_name: Option<bool>,
+  preserve_function_length: Option<bool>,
+  iterate_side_effects: Option<bool>,
+  unknown_property_read_side_effects: Option<bool>,
+  unmatched_prototype_property_as_undefined: Option<bool>,
+
+  min_simple_number_value: Option<i64>,
+  max_simple_number_value: Option<i64>,
+  max_simple_string_length: Option<usize>,
+
+  manual_pure_functions: Option<Vec<String>>,
+  define: Option<FxHashMap<String, String>>,
+  import_meta: Option<FxHashMap<String, Option<String>>>,
+}
+
+impl TreeShakeConfigFile {
+  fn apply(self, base: TreeShakeConfig) -> Result<TreeShakeConfig, String> {
+    let mut config = match &self.preset {
+      Some(preset) => {
+        TreeShakeConfig::preset(preset).ok_or_else(|| format!("Invalid preset: {}", preset))?
+      }
+      None => base,
+    };
+
+    let compile =
+      |regex: &str| Regex::new(regex).map_err(|e| format!("Invalid mangle regex {}: {}", regex, e));
+    if let Some(regex) = &self.mangle_include {
+      config.mangle_include = Some(compile(regex)?);
+    }
+    if let Some(regex) = &self.mangle_exclude {
+      config.mangle_exclude = Some(compile(regex)?);
+    }
+    if let Some(yes) = self.always_inline_literal {
+      config = config.with_always_inline_literal(yes);
+    }
+
+    macro_rules! override_fields {
+      ($($field:ident),*) => {
+        $(
+          if let Some(value) = self.$field {
+            config.$field = value;
+          }
+        )*
+      };
+    }
+    override_fields!(
+      jsx,
+      max_recursion_depth,
+      mangling,
+      mangle_reserved,
+      unknown_global_side_effects,
+      preserve_function_name,
+      preserve_function_length,
+      iterate_side_effects,
+      unknown_property_read_side_effects,
+      unmatched_prototype_property_as_undefined,
+      min_simple_number_value,
+      max_simple_number_value,
+      max_simple_string_length,
+      manual_pure_functions,
+      define,
+      import_meta
+    );
+    config.validate()?;
+    Ok(config)
+  }
+}
+
+impl TryFrom<TreeShakeConfigFile> for TreeShakeConfig {
+  type Error = String;
+
+  fn try_from(file: TreeShakeConfigFile) -> Result<Self, Self::Error> {
+    file.apply(Self::default())
+  }
+}
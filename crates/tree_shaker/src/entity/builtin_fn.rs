@@ -3,7 +3,7 @@ use super::{
   ObjectEntity, TypeofResult,
 };
 use crate::{analyzer::Analyzer, consumable::Consumable};
-use std::fmt::Debug;
+use std::{fmt::Debug, marker::PhantomData};
 
 pub trait BuiltinFnEntity<'a>: Debug {
   #[cfg(feature = "flame")]
@@ -18,6 +18,15 @@ pub trait BuiltinFnEntity<'a>: Debug {
     this: Entity<'a>,
     args: Entity<'a>,
   ) -> Entity<'a>;
+  /// `None` if the builtin is not a known constructor
+  fn construct_impl(
+    &self,
+    _analyzer: &mut Analyzer<'a>,
+    _dep: Consumable<'a>,
+    _args: Entity<'a>,
+  ) -> Option<Entity<'a>> {
+    None
+  }
 }
 
 impl<'a, T: BuiltinFnEntity<'a>> EntityTrait<'a> for T {
@@ -96,7 +105,9 @@ impl<'a, T: BuiltinFnEntity<'a>> EntityTrait<'a> for T {
     dep: Consumable<'a>,
     args: Entity<'a>,
   ) -> Entity<'a> {
-    consumed_object::construct(self, analyzer, dep, args)
+    self
+      .construct_impl(analyzer, dep, args)
+      .unwrap_or_else(|| consumed_object::construct(self, analyzer, dep, args))
   }
 
   fn jsx(&'a self, analyzer: &mut Analyzer<'a>, props: Entity<'a>) -> Entity<'a> {
@@ -216,6 +227,22 @@ impl<'a> EntityFactory<'a> {
       object: None,
     })
   }
+
+  /// A builtin function which also has static properties, like `Number`
+  #[cfg_attr(not(feature = "flame"), allow(unused_variables))]
+  pub fn implemented_builtin_fn_with_object<F: BuiltinFnImplementation<'a> + 'a>(
+    &self,
+    name: &'static str,
+    object: &'a ObjectEntity<'a>,
+    implementation: F,
+  ) -> Entity<'a> {
+    self.alloc(ImplementedBuiltinFnEntity {
+      #[cfg(feature = "flame")]
+      name,
+      implementation,
+      object: Some(object),
+    })
+  }
 }
 
 impl<'a> Analyzer<'a> {
@@ -233,6 +260,76 @@ impl<'a> Analyzer<'a> {
   }
 }
 
+pub trait BuiltinConstructorImplementation<'a>:
+  Fn(&mut Analyzer<'a>, Consumable<'a>, Entity<'a>) -> Entity<'a>
+{
+}
+impl<'a, T: Fn(&mut Analyzer<'a>, Consumable<'a>, Entity<'a>) -> Entity<'a>>
+  BuiltinConstructorImplementation<'a> for T
+{
+}
+
+/// A builtin which can only be called with `new`
+#[derive(Clone, Copy)]
+pub struct ImplementedBuiltinConstructorEntity<'a, F: BuiltinConstructorImplementation<'a> + 'a> {
+  #[cfg(feature = "flame")]
+  name: &'static str,
+  implementation: F,
+  phantom: PhantomData<&'a ()>,
+}
+
+impl<'a, F: BuiltinConstructorImplementation<'a> + 'a> Debug
+  for ImplementedBuiltinConstructorEntity<'a, F>
+{
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("ImplementedBuiltinConstructorEntity").finish()
+  }
+}
+
+impl<'a, F: BuiltinConstructorImplementation<'a> + 'a> BuiltinFnEntity<'a>
+  for ImplementedBuiltinConstructorEntity<'a, F>
+{
+  #[cfg(feature = "flame")]
+  fn name(&self) -> &'static str {
+    self.name
+  }
+  fn call_impl(
+    &self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Consumable<'a>,
+    this: Entity<'a>,
+    args: Entity<'a>,
+  ) -> Entity<'a> {
+    analyzer.consume((dep, this, args));
+    analyzer.thrown_builtin_error("Constructor cannot be invoked without 'new'");
+    analyzer.factory.unknown()
+  }
+  fn construct_impl(
+    &self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Consumable<'a>,
+    args: Entity<'a>,
+  ) -> Option<Entity<'a>> {
+    Some((self.implementation)(analyzer, dep, args))
+  }
+}
+
+impl<'a> EntityFactory<'a> {
+  #[cfg_attr(not(feature = "flame"), allow(unused_variables))]
+  pub fn implemented_builtin_constructor<F: BuiltinConstructorImplementation<'a> + 'a>(
+    &self,
+    name: &'static str,
+    implementation: F,
+  ) -> Entity<'a> {
+    self.alloc(ImplementedBuiltinConstructorEntity {
+      #[cfg(feature = "flame")]
+      name,
+      implementation,
+      phantom: PhantomData,
+    })
+  }
+}
+
 #[derive(Debug, Clone)]
 pub struct PureBuiltinFnEntity<'a> {
   return_value: fn(&EntityFactory<'a>) -> Entity<'a>,
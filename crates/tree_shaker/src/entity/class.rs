@@ -1,8 +1,8 @@
 use super::{
   consumed_object, Entity, EntityFactory, EntityTrait, EnumeratedProperties, IteratedElements,
-  ObjectEntity, TypeofResult,
+  ObjectEntity, ObjectPropertyValue, TypeofResult,
 };
-use crate::{analyzer::Analyzer, consumable::Consumable, use_consumed_flag};
+use crate::{analyzer::Analyzer, consumable::Consumable, use_consumed_flag, utils::CalleeNode};
 use oxc::{ast::ast::Class, semantic::ScopeId};
 use std::{cell::Cell, rc::Rc};
 
@@ -61,6 +61,17 @@ impl<'a> EntityTrait<'a> for ClassEntity<'a> {
     self.statics.delete_property(analyzer, dep, key)
   }
 
+  fn define_property(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Consumable<'a>,
+    key: Entity<'a>,
+    value: ObjectPropertyValue<'a>,
+    enumerable: bool,
+  ) {
+    self.statics.define_property(analyzer, dep, key, value, enumerable)
+  }
+
   fn enumerate_properties(
     &'a self,
     analyzer: &mut Analyzer<'a>,
@@ -86,10 +97,27 @@ impl<'a> EntityTrait<'a> for ClassEntity<'a> {
     dep: Consumable<'a>,
     args: Entity<'a>,
   ) -> Entity<'a> {
-    consumed_object::construct(self, analyzer, dep, args)
+    if self.consumed.get() || self.super_class.is_some() {
+      return consumed_object::construct(self, analyzer, dep, args);
+    }
+
+    let mut recursion_depth = 0usize;
+    for scope in analyzer.scope_context.call.iter().rev() {
+      if scope.callee.node == CalleeNode::ClassConstructor(self.node) {
+        recursion_depth += 1;
+        if recursion_depth >= analyzer.config.max_recursion_depth {
+          return consumed_object::construct(self, analyzer, dep, args);
+        }
+      }
+    }
+
+    analyzer.construct_class_instance(self, dep, args)
   }
 
   fn jsx(&'a self, analyzer: &mut Analyzer<'a>, props: Entity<'a>) -> Entity<'a> {
+    if !self.consumed.get() && analyzer.is_react_class_component(self) {
+      return analyzer.render_react_class_component(self, props);
+    }
     consumed_object::jsx(self, analyzer, props)
   }
 
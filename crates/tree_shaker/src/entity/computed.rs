@@ -1,6 +1,6 @@
 use super::{
   Entity, EntityFactory, EntityTrait, EnumeratedProperties, IteratedElements, LiteralEntity,
-  TypeofResult,
+  ObjectPropertyValue, TypeofResult,
 };
 use crate::{
   analyzer::Analyzer,
@@ -71,6 +71,17 @@ impl<'a, T: ConsumableTrait<'a> + Copy + 'a> EntityTrait<'a> for ComputedEntity<
     self.val.delete_property(analyzer, self.forward_dep(dep, analyzer), key)
   }
 
+  fn define_property(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Consumable<'a>,
+    key: Entity<'a>,
+    value: ObjectPropertyValue<'a>,
+    enumerable: bool,
+  ) {
+    self.val.define_property(analyzer, self.forward_dep(dep, analyzer), key, value, enumerable)
+  }
+
   fn call(
     &'a self,
     analyzer: &mut Analyzer<'a>,
@@ -1,4 +1,4 @@
-use super::{Entity, EnumeratedProperties, IteratedElements};
+use super::{Entity, EnumeratedProperties, IteratedElements, ObjectPropertyValue};
 use crate::{analyzer::Analyzer, consumable::Consumable};
 
 pub fn unknown_mutate<'a>(analyzer: &mut Analyzer<'a>, dep: Consumable<'a>) {
@@ -37,6 +37,17 @@ pub fn set_property<'a>(
   analyzer.consume((dep, key, value));
 }
 
+pub fn define_property<'a>(
+  analyzer: &mut Analyzer<'a>,
+  dep: Consumable<'a>,
+  key: Entity<'a>,
+  value: ObjectPropertyValue<'a>,
+) {
+  analyzer.may_throw();
+  analyzer.refer_to_global();
+  analyzer.consume((dep, key, value));
+}
+
 pub fn enumerate_properties<'a>(
   target: Entity<'a>,
   analyzer: &mut Analyzer<'a>,
@@ -1,6 +1,6 @@
 use super::{
   consumed_object, Entity, EntityTrait, EnumeratedProperties, IteratedElements, ObjectEntity,
-  TypeofResult,
+  ObjectPropertyValue, TypeofResult,
 };
 use crate::{
   analyzer::Analyzer,
@@ -18,6 +18,14 @@ pub struct FunctionEntity<'a> {
   pub callee: CalleeInfo<'a>,
   pub variable_scope_stack: Rc<Vec<ScopeId>>,
   pub finite_recursion: bool,
+  /// Annotated with `@__RECURSION_DEPTH__(n)`
+  pub recursion_depth: Option<usize>,
+  /// Annotated with `@__NO_SIDE_EFFECTS__`
+  pub no_side_effects: bool,
+  /// Annotated with `@__NOINLINE__`
+  pub no_inline: bool,
+  /// Annotated with `@__ALWAYS_ANALYZE__`
+  pub always_analyze: bool,
   pub object: &'a ObjectEntity<'a>,
 }
 
@@ -58,6 +66,17 @@ impl<'a> EntityTrait<'a> for FunctionEntity<'a> {
     self.object.delete_property(analyzer, self.forward_dep(dep, analyzer), key);
   }
 
+  fn define_property(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Consumable<'a>,
+    key: Entity<'a>,
+    value: ObjectPropertyValue<'a>,
+    enumerable: bool,
+  ) {
+    self.object.define_property(analyzer, self.forward_dep(dep, analyzer), key, value, enumerable);
+  }
+
   fn enumerate_properties(
     &'a self,
     analyzer: &mut Analyzer<'a>,
@@ -76,16 +95,28 @@ impl<'a> EntityTrait<'a> for FunctionEntity<'a> {
     this: Entity<'a>,
     args: Entity<'a>,
   ) -> Entity<'a> {
-    if self.consumed.get() {
+    if self.no_side_effects {
+      // The call is pure, so the body is only analyzed if the returned value is used
+      return analyzer.factory.computed_unknown(analyzer.consumable((self, dep, this, args)));
+    }
+
+    if self.no_inline {
+      // The function is opaque, as if it is an unknown function
+      self.consume(analyzer);
+      return consumed_object::call(self, analyzer, dep, this, args);
+    }
+
+    if self.consumed.get() && !self.always_analyze {
       return consumed_object::call(self, analyzer, dep, this, args);
     }
 
     if !self.finite_recursion {
+      let max_recursion_depth = self.recursion_depth.unwrap_or(analyzer.config.max_recursion_depth);
       let mut recursion_depth = 0usize;
       for scope in analyzer.scope_context.call.iter().rev() {
         if scope.callee.node == self.callee.node {
           recursion_depth += 1;
-          if recursion_depth >= analyzer.config.max_recursion_depth {
+          if recursion_depth >= max_recursion_depth {
             self.consume_body(analyzer);
             return consumed_object::call(self, analyzer, dep, this, args);
           }
@@ -244,6 +275,10 @@ impl<'a> Analyzer<'a> {
       callee: self.new_callee_info(node),
       variable_scope_stack: Rc::new(self.scope_context.variable.stack.clone()),
       finite_recursion: self.has_finite_recursion_notation(node.span()),
+      recursion_depth: self.get_recursion_depth_notation(node.span()),
+      no_side_effects: self.has_no_side_effects_notation(node.span()),
+      no_inline: self.has_no_inline_notation(node.span()),
+      always_analyze: self.has_always_analyze_notation(node.span()),
       object: self.new_function_object(),
     });
 
@@ -481,7 +481,7 @@ impl<'a> LiteralEntity<'a> {
     let val = analyzer.factory.alloc(self);
     match self {
       LiteralEntity::String(value, None) => {
-        let atom = existing_atom.get_or_insert_with(|| analyzer.mangler.new_atom());
+        let atom = existing_atom.get_or_insert_with(|| analyzer.mangler.new_atom(value));
         analyzer.factory.alloc(LiteralEntity::String(value, Some(*atom)))
       }
       LiteralEntity::String(_, Some(atom)) => {
@@ -14,7 +14,10 @@ mod logical_result;
 mod object;
 mod operations;
 mod primitive;
+mod promise;
+mod proxy;
 mod react_element;
+mod react_fragment;
 mod symbol;
 mod typeof_result;
 mod union;
@@ -73,6 +76,18 @@ pub trait EntityTrait<'a>: Debug {
     dep: Consumable<'a>,
   ) -> EnumeratedProperties<'a>;
   fn delete_property(&'a self, analyzer: &mut Analyzer<'a>, dep: Consumable<'a>, key: Entity<'a>);
+  /// Defines a field or accessor without calling setters, e.g. via `Object.defineProperty`
+  fn define_property(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Consumable<'a>,
+    key: Entity<'a>,
+    value: ObjectPropertyValue<'a>,
+    _enumerable: bool,
+  ) {
+    self.consume(analyzer);
+    consumed_object::define_property(analyzer, dep, key, value)
+  }
   fn call(
     &'a self,
     analyzer: &mut Analyzer<'a>,
@@ -0,0 +1,94 @@
+use super::{ObjectEntity, ObjectProperty, ObjectPropertyValue};
+use crate::{
+  analyzer::Analyzer,
+  consumable::{Consumable, ConsumableCollector},
+  entity::{consumed_object, Entity, EntityTrait, LiteralEntity},
+};
+
+impl<'a> ObjectEntity<'a> {
+  /// `Object.defineProperty`. Unlike `set_property`, setters are not called.
+  pub fn define_property(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Consumable<'a>,
+    key: Entity<'a>,
+    value: ObjectPropertyValue<'a>,
+    enumerable: bool,
+  ) {
+    if self.consumed.get() {
+      return consumed_object::define_property(analyzer, dep, key, value);
+    }
+
+    let (has_exhaustive, mut indeterminate, exec_deps) =
+      analyzer.pre_mutate_object(self.cf_scope, self.object_id);
+
+    // Symbol keys are not tracked, so the property is defined on the consumed object
+    let has_symbol_key = key.get_to_literals(analyzer).is_some_and(|key_literals| {
+      key_literals.iter().any(|key_literal| matches!(key_literal, LiteralEntity::Symbol(_, _)))
+    });
+
+    if has_exhaustive || has_symbol_key {
+      self.consume(analyzer);
+      return consumed_object::define_property(analyzer, dep, key, value);
+    }
+
+    // Keys defined via descriptors are not tracked by the mangler
+    self.disable_mangling(analyzer);
+
+    let dep = analyzer.consumable((exec_deps, dep, key));
+    let value = match value {
+      ObjectPropertyValue::Field(value, readonly) => {
+        ObjectPropertyValue::Field(analyzer.factory.computed(value, dep), readonly)
+      }
+      ObjectPropertyValue::Property(getter, setter) => ObjectPropertyValue::Property(
+        getter.map(|getter| analyzer.factory.computed(getter, dep)),
+        setter.map(|setter| analyzer.factory.computed(setter, dep)),
+      ),
+    };
+
+    if let Some(key_literals) = key.get_to_literals(analyzer) {
+      indeterminate |= key_literals.len() > 1;
+
+      let mut string_keyed = self.string_keyed.borrow_mut();
+      let mut rest = self.rest.borrow_mut();
+      for key_literal in key_literals {
+        match key_literal {
+          LiteralEntity::String(key_str, _) => {
+            if let Some(property) = string_keyed.get_mut(key_str) {
+              if indeterminate {
+                property.possible_values.push(value);
+              } else {
+                property.possible_values = vec![value];
+                property.non_existent.force_clear();
+                property.non_enumerable.force_clear();
+              }
+              if !enumerable {
+                property.non_enumerable.push(dep);
+              }
+            } else if let Some(rest) = &mut *rest {
+              rest.possible_values.push(value);
+            } else {
+              string_keyed.insert(
+                key_str,
+                ObjectProperty {
+                  definite: !indeterminate,
+                  possible_values: vec![value],
+                  non_existent: ConsumableCollector::default(),
+                  non_enumerable: ConsumableCollector::new(if enumerable {
+                    vec![]
+                  } else {
+                    vec![dep]
+                  }),
+                  mangling: None,
+                },
+              );
+            }
+          }
+          _ => unreachable!("Invalid property key"),
+        }
+      }
+    } else {
+      self.unknown_keyed.borrow_mut().possible_values.push(value);
+    }
+  }
+}
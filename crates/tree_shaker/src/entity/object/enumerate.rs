@@ -53,7 +53,8 @@ impl<'a> ObjectEntity<'a> {
         let mut string_keyed = self.string_keyed.borrow_mut();
         let properties = string_keyed.get_mut(&key).unwrap();
 
-        let definite = properties.definite;
+        let definite = properties.definite && properties.non_enumerable.is_empty();
+        let non_enumerable = properties.non_enumerable.try_collect(analyzer.factory);
         let key_entity = if mangable {
           analyzer.factory.mangable_string(key, properties.mangling.unwrap().1)
         } else {
@@ -69,6 +70,11 @@ impl<'a> ObjectEntity<'a> {
         }
 
         if let Some(value) = analyzer.factory.try_union(values) {
+          let value = if let Some(non_enumerable) = non_enumerable {
+            analyzer.factory.computed(value, non_enumerable)
+          } else {
+            value
+          };
           result.push((definite, key_entity, value));
         }
       }
@@ -3,6 +3,7 @@ use crate::{
   analyzer::Analyzer,
   consumable::Consumable,
   entity::{consumed_object, Entity, LiteralEntity},
+  mangling::MangleConstraint,
   scope::CfScopeKind,
 };
 
@@ -51,6 +52,12 @@ impl<'a> ObjectEntity<'a> {
                 values.push(if mangable { analyzer.factory.computed(val, key_atom) } else { val });
               } else {
                 may_add_undefined = true;
+                if mangable {
+                  // The key must not be mangled to any existing key
+                  let group = self.mangling_group.unwrap().get().unwrap();
+                  let constraint = MangleConstraint::Unique(group, key_atom.unwrap());
+                  non_existent.push(analyzer.consumable(&*analyzer.factory.alloc(constraint)));
+                }
               }
             }
           }
@@ -71,6 +71,7 @@ impl<'a> ObjectEntity<'a> {
                 definite,
                 possible_values: vec![property_val],
                 non_existent: ConsumableCollector::default(),
+                non_enumerable: ConsumableCollector::default(),
                 mangling: mangable.then(|| (key, key_atom.unwrap())),
               };
               string_keyed.insert(key_str, property);
@@ -117,6 +118,7 @@ impl<'a> ObjectEntity<'a> {
         definite: false,
         possible_values: vec![property],
         non_existent: ConsumableCollector::default(),
+        non_enumerable: ConsumableCollector::default(),
         mangling: None,
       });
     }
@@ -1,3 +1,4 @@
+mod define;
 mod delete;
 mod enumerate;
 mod get;
@@ -63,6 +64,9 @@ impl<'a> EntityTrait<'a> for ObjectEntity<'a> {
       property.consume(analyzer);
     }
     self.unknown_keyed.take().consume(analyzer);
+    if let Some(rest) = self.rest.take() {
+      rest.consume(analyzer);
+    }
 
     analyzer.mark_object_consumed(self.cf_scope, self.object_id);
   }
@@ -106,6 +110,17 @@ impl<'a> EntityTrait<'a> for ObjectEntity<'a> {
     self.delete_property(analyzer, dep, key);
   }
 
+  fn define_property(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Consumable<'a>,
+    key: Entity<'a>,
+    value: ObjectPropertyValue<'a>,
+    enumerable: bool,
+  ) {
+    self.define_property(analyzer, dep, key, value, enumerable);
+  }
+
   fn call(
     &'a self,
     analyzer: &mut Analyzer<'a>,
@@ -130,8 +145,18 @@ impl<'a> EntityTrait<'a> for ObjectEntity<'a> {
   }
 
   fn r#await(&'a self, analyzer: &mut Analyzer<'a>, dep: Consumable<'a>) -> Entity<'a> {
-    self.consume(analyzer);
-    consumed_object::r#await(analyzer, dep)
+    if self.consumed.get() {
+      return consumed_object::r#await(analyzer, dep);
+    }
+    let then = self.get_property(analyzer, dep, analyzer.factory.string("then"));
+    if then.test_typeof().contains(TypeofResult::Function) {
+      // Possibly a thenable
+      self.consume(analyzer);
+      analyzer.consume(then);
+      consumed_object::r#await(analyzer, dep)
+    } else {
+      analyzer.factory.computed(self, then)
+    }
   }
 
   fn iterate(&'a self, analyzer: &mut Analyzer<'a>, dep: Consumable<'a>) -> IteratedElements<'a> {
@@ -254,6 +279,7 @@ impl<'a> Analyzer<'a> {
           false,
         )],
         non_existent: Default::default(),
+        non_enumerable: Default::default(),
         mangling: None,
       },
     );
@@ -1,6 +1,6 @@
 use crate::{
   analyzer::Analyzer,
-  consumable::{Consumable, ConsumableCollector, ConsumableVec},
+  consumable::{Consumable, ConsumableCollector, ConsumableTrait, ConsumableVec},
   entity::Entity,
   mangling::{MangleAtom, MangleConstraint},
 };
@@ -18,6 +18,7 @@ pub struct ObjectProperty<'a> {
   pub definite: bool,                                // 是否一定存在
   pub possible_values: Vec<ObjectPropertyValue<'a>>, // 可能的值，可能有多个
   pub non_existent: ConsumableCollector<'a>,         // 如果不存在，为什么？
+  pub non_enumerable: ConsumableCollector<'a>,       // 如果不可枚举，为什么？
   pub mangling: Option<(Entity<'a>, MangleAtom)>,
 }
 
@@ -27,11 +28,24 @@ impl<'a> Default for ObjectProperty<'a> {
       definite: true,
       possible_values: vec![],
       non_existent: ConsumableCollector::default(),
+      non_enumerable: ConsumableCollector::default(),
       mangling: None,
     }
   }
 }
 
+impl<'a> ConsumableTrait<'a> for ObjectPropertyValue<'a> {
+  fn consume(&self, analyzer: &mut Analyzer<'a>) {
+    match self {
+      ObjectPropertyValue::Field(value, _) => analyzer.consume(*value),
+      ObjectPropertyValue::Property(getter, setter) => {
+        analyzer.consume(*getter);
+        analyzer.consume(*setter);
+      }
+    }
+  }
+}
+
 impl<'a> ObjectProperty<'a> {
   pub fn get(
     &mut self,
@@ -138,16 +152,11 @@ impl<'a> ObjectProperty<'a> {
 
   pub fn consume(self, analyzer: &mut Analyzer<'a>) {
     for possible_value in self.possible_values {
-      match possible_value {
-        ObjectPropertyValue::Field(value, _) => analyzer.consume(value),
-        ObjectPropertyValue::Property(getter, setter) => {
-          analyzer.consume(getter);
-          analyzer.consume(setter);
-        }
-      }
+      analyzer.consume(possible_value);
     }
 
     self.non_existent.consume_all(analyzer);
+    self.non_enumerable.consume_all(analyzer);
 
     if let Some((key, _)) = self.mangling {
       analyzer.consume(key);
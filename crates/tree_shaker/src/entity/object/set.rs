@@ -85,6 +85,7 @@ impl<'a> ObjectEntity<'a> {
                   definite: !indeterminate,
                   possible_values: vec![ObjectPropertyValue::Field(value, false)],
                   non_existent: ConsumableCollector::default(),
+                  non_enumerable: ConsumableCollector::default(),
                   mangling: mangable.then(|| (key, key_atom.unwrap())),
                 },
               );
@@ -0,0 +1,166 @@
+use super::{
+  consumed_object, Entity, EntityFactory, EntityTrait, EnumeratedProperties, IteratedElements,
+  TypeofResult,
+};
+use crate::{analyzer::Analyzer, consumable::Consumable, use_consumed_flag};
+use std::cell::Cell;
+
+/// The promise returned by calling an async function.
+#[derive(Debug)]
+pub struct PromiseEntity<'a> {
+  consumed: Cell<bool>,
+  pub value: Entity<'a>,
+  /// `Some` if the promise may be rejected
+  pub errors: Option<Vec<Entity<'a>>>,
+}
+
+impl<'a> EntityTrait<'a> for PromiseEntity<'a> {
+  fn consume(&'a self, analyzer: &mut Analyzer<'a>) {
+    use_consumed_flag!(self);
+
+    self.value.consume(analyzer);
+    if let Some(errors) = &self.errors {
+      for error in errors {
+        error.consume(analyzer);
+      }
+    }
+  }
+
+  fn unknown_mutate(&'a self, analyzer: &mut Analyzer<'a>, dep: Consumable<'a>) {
+    self.consume(analyzer);
+    consumed_object::unknown_mutate(analyzer, dep);
+  }
+
+  fn get_property(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Consumable<'a>,
+    key: Entity<'a>,
+  ) -> Entity<'a> {
+    if self.consumed.get() {
+      return consumed_object::get_property(self, analyzer, dep, key);
+    }
+    analyzer.builtins.prototypes.promise.get_property(analyzer, self, key, dep)
+  }
+
+  fn set_property(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Consumable<'a>,
+    key: Entity<'a>,
+    value: Entity<'a>,
+  ) {
+    self.consume(analyzer);
+    consumed_object::set_property(analyzer, dep, key, value)
+  }
+
+  fn enumerate_properties(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Consumable<'a>,
+  ) -> EnumeratedProperties<'a> {
+    if self.consumed.get() {
+      return consumed_object::enumerate_properties(self, analyzer, dep);
+    }
+    (vec![], dep)
+  }
+
+  fn delete_property(&'a self, analyzer: &mut Analyzer<'a>, dep: Consumable<'a>, key: Entity<'a>) {
+    self.consume(analyzer);
+    consumed_object::delete_property(analyzer, dep, key)
+  }
+
+  fn call(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Consumable<'a>,
+    this: Entity<'a>,
+    args: Entity<'a>,
+  ) -> Entity<'a> {
+    analyzer.thrown_builtin_error("Promise is not a function");
+    consumed_object::call(self, analyzer, dep, this, args)
+  }
+
+  fn construct(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Consumable<'a>,
+    args: Entity<'a>,
+  ) -> Entity<'a> {
+    analyzer.thrown_builtin_error("Promise is not a constructor");
+    consumed_object::construct(self, analyzer, dep, args)
+  }
+
+  fn jsx(&'a self, analyzer: &mut Analyzer<'a>, props: Entity<'a>) -> Entity<'a> {
+    consumed_object::jsx(self, analyzer, props)
+  }
+
+  fn r#await(&'a self, analyzer: &mut Analyzer<'a>, dep: Consumable<'a>) -> Entity<'a> {
+    if self.consumed.get() {
+      return consumed_object::r#await(analyzer, dep);
+    }
+    if let Some(errors) = &self.errors {
+      analyzer.forward_throw(errors.clone());
+    }
+    // The resolved value may be a thenable itself
+    self.value.r#await(analyzer, dep)
+  }
+
+  fn iterate(&'a self, analyzer: &mut Analyzer<'a>, dep: Consumable<'a>) -> IteratedElements<'a> {
+    analyzer.thrown_builtin_error("Promise is not iterable");
+    self.consume(analyzer);
+    consumed_object::iterate(analyzer, dep)
+  }
+
+  fn get_destructable(&'a self, analyzer: &Analyzer<'a>, dep: Consumable<'a>) -> Consumable<'a> {
+    analyzer.consumable((self, dep))
+  }
+
+  fn get_typeof(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    analyzer.factory.string("object")
+  }
+
+  fn get_to_string(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    if self.consumed.get() {
+      return consumed_object::get_to_string(analyzer);
+    }
+    analyzer.factory.string("[object Promise]")
+  }
+
+  fn get_to_numeric(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    if self.consumed.get() {
+      return consumed_object::get_to_numeric(analyzer);
+    }
+    analyzer.factory.nan
+  }
+
+  fn get_to_boolean(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    analyzer.factory.boolean(true)
+  }
+
+  fn get_to_property_key(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    self.get_to_string(analyzer)
+  }
+
+  fn get_to_jsx_child(&'a self, _analyzer: &Analyzer<'a>) -> Entity<'a> {
+    self
+  }
+
+  fn test_typeof(&self) -> TypeofResult {
+    TypeofResult::Object
+  }
+
+  fn test_truthy(&self) -> Option<bool> {
+    Some(true)
+  }
+
+  fn test_nullish(&self) -> Option<bool> {
+    Some(false)
+  }
+}
+
+impl<'a> EntityFactory<'a> {
+  pub fn promise(&self, value: Entity<'a>, errors: Option<Vec<Entity<'a>>>) -> Entity<'a> {
+    self.alloc(PromiseEntity { consumed: Cell::new(false), value, errors })
+  }
+}
@@ -0,0 +1,265 @@
+use super::{
+  consumed_object, Entity, EntityFactory, EntityTrait, EnumeratedProperties, IteratedElements,
+  TypeofResult,
+};
+use crate::{analyzer::Analyzer, consumable::Consumable, use_consumed_flag};
+use std::cell::Cell;
+
+/// `new Proxy(target, handler)`
+#[derive(Debug)]
+pub struct ProxyEntity<'a> {
+  consumed: Cell<bool>,
+  pub target: Entity<'a>,
+  pub handler: Entity<'a>,
+}
+
+enum ProxyTrap<'a> {
+  /// The handler has the trap
+  Present(Entity<'a>),
+  /// The handler doesn't have the trap, so the operation is forwarded to the target
+  Absent(Consumable<'a>),
+  Unknown,
+}
+
+impl<'a> EntityTrait<'a> for ProxyEntity<'a> {
+  fn consume(&'a self, analyzer: &mut Analyzer<'a>) {
+    use_consumed_flag!(self);
+
+    self.target.consume(analyzer);
+    self.handler.consume(analyzer);
+  }
+
+  fn unknown_mutate(&'a self, analyzer: &mut Analyzer<'a>, dep: Consumable<'a>) {
+    self.consume(analyzer);
+    consumed_object::unknown_mutate(analyzer, dep);
+  }
+
+  fn get_property(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Consumable<'a>,
+    key: Entity<'a>,
+  ) -> Entity<'a> {
+    match self.get_trap(analyzer, dep, "get") {
+      ProxyTrap::Present(trap) => {
+        let args =
+          analyzer.factory.arguments(vec![(false, self.target), (false, key), (false, self)]);
+        trap.call(analyzer, dep, self.handler, args)
+      }
+      ProxyTrap::Absent(trap_dep) => {
+        self.target.get_property(analyzer, analyzer.consumable((trap_dep, dep)), key)
+      }
+      ProxyTrap::Unknown => {
+        self.consume(analyzer);
+        consumed_object::get_property(self, analyzer, dep, key)
+      }
+    }
+  }
+
+  fn set_property(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Consumable<'a>,
+    key: Entity<'a>,
+    value: Entity<'a>,
+  ) {
+    match self.get_trap(analyzer, dep, "set") {
+      ProxyTrap::Present(trap) => {
+        let args = analyzer.factory.arguments(vec![
+          (false, self.target),
+          (false, key),
+          (false, value),
+          (false, self),
+        ]);
+        let result = trap.call(analyzer, dep, self.handler, args);
+        // A falsy result throws in strict mode
+        if result.test_truthy() != Some(true) {
+          analyzer.consume(result);
+          analyzer.may_throw();
+        }
+      }
+      ProxyTrap::Absent(trap_dep) => {
+        self.target.set_property(analyzer, analyzer.consumable((trap_dep, dep)), key, value)
+      }
+      ProxyTrap::Unknown => {
+        self.consume(analyzer);
+        consumed_object::set_property(analyzer, dep, key, value)
+      }
+    }
+  }
+
+  fn enumerate_properties(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Consumable<'a>,
+  ) -> EnumeratedProperties<'a> {
+    // The `ownKeys` trap only provides keys, so only the absent case is handled precisely
+    match self.get_trap(analyzer, dep, "ownKeys") {
+      ProxyTrap::Absent(trap_dep) => {
+        self.target.enumerate_properties(analyzer, analyzer.consumable((trap_dep, dep)))
+      }
+      _ => {
+        self.consume(analyzer);
+        consumed_object::enumerate_properties(self, analyzer, dep)
+      }
+    }
+  }
+
+  fn delete_property(&'a self, analyzer: &mut Analyzer<'a>, dep: Consumable<'a>, key: Entity<'a>) {
+    match self.get_trap(analyzer, dep, "deleteProperty") {
+      ProxyTrap::Present(trap) => {
+        let args = analyzer.factory.arguments(vec![(false, self.target), (false, key)]);
+        trap.call(analyzer, dep, self.handler, args);
+      }
+      ProxyTrap::Absent(trap_dep) => {
+        self.target.delete_property(analyzer, analyzer.consumable((trap_dep, dep)), key)
+      }
+      ProxyTrap::Unknown => {
+        self.consume(analyzer);
+        consumed_object::delete_property(analyzer, dep, key)
+      }
+    }
+  }
+
+  fn call(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Consumable<'a>,
+    this: Entity<'a>,
+    args: Entity<'a>,
+  ) -> Entity<'a> {
+    match self.get_trap(analyzer, dep, "apply") {
+      ProxyTrap::Present(trap) => {
+        let args_array = args_to_array(analyzer, dep, args);
+        let args = analyzer.factory.arguments(vec![
+          (false, self.target),
+          (false, this),
+          (false, args_array),
+        ]);
+        trap.call(analyzer, dep, self.handler, args)
+      }
+      ProxyTrap::Absent(trap_dep) => {
+        self.target.call(analyzer, analyzer.consumable((trap_dep, dep)), this, args)
+      }
+      ProxyTrap::Unknown => consumed_object::call(self, analyzer, dep, this, args),
+    }
+  }
+
+  fn construct(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Consumable<'a>,
+    args: Entity<'a>,
+  ) -> Entity<'a> {
+    match self.get_trap(analyzer, dep, "construct") {
+      ProxyTrap::Present(trap) => {
+        let args_array = args_to_array(analyzer, dep, args);
+        let args = analyzer.factory.arguments(vec![
+          (false, self.target),
+          (false, args_array),
+          (false, self),
+        ]);
+        trap.call(analyzer, dep, self.handler, args)
+      }
+      ProxyTrap::Absent(trap_dep) => {
+        self.target.construct(analyzer, analyzer.consumable((trap_dep, dep)), args)
+      }
+      ProxyTrap::Unknown => consumed_object::construct(self, analyzer, dep, args),
+    }
+  }
+
+  fn jsx(&'a self, analyzer: &mut Analyzer<'a>, props: Entity<'a>) -> Entity<'a> {
+    consumed_object::jsx(self, analyzer, props)
+  }
+
+  fn r#await(&'a self, analyzer: &mut Analyzer<'a>, dep: Consumable<'a>) -> Entity<'a> {
+    self.consume(analyzer);
+    consumed_object::r#await(analyzer, dep)
+  }
+
+  fn iterate(&'a self, analyzer: &mut Analyzer<'a>, dep: Consumable<'a>) -> IteratedElements<'a> {
+    self.consume(analyzer);
+    consumed_object::iterate(analyzer, dep)
+  }
+
+  fn get_destructable(&'a self, analyzer: &Analyzer<'a>, dep: Consumable<'a>) -> Consumable<'a> {
+    analyzer.consumable((self, dep))
+  }
+
+  fn get_typeof(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    self.target.get_typeof(analyzer)
+  }
+
+  fn get_to_string(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    analyzer.factory.computed_unknown_string(self)
+  }
+
+  fn get_to_numeric(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    analyzer.factory.computed_unknown(self)
+  }
+
+  fn get_to_boolean(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    analyzer.factory.boolean(true)
+  }
+
+  fn get_to_property_key(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    self.get_to_string(analyzer)
+  }
+
+  fn get_to_jsx_child(&'a self, _analyzer: &Analyzer<'a>) -> Entity<'a> {
+    self
+  }
+
+  fn test_typeof(&self) -> TypeofResult {
+    self.target.test_typeof()
+  }
+
+  fn test_truthy(&self) -> Option<bool> {
+    Some(true)
+  }
+
+  fn test_nullish(&self) -> Option<bool> {
+    Some(false)
+  }
+}
+
+impl<'a> ProxyEntity<'a> {
+  fn get_trap(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Consumable<'a>,
+    name: &'static str,
+  ) -> ProxyTrap<'a> {
+    if self.consumed.get() {
+      return ProxyTrap::Unknown;
+    }
+    let trap = self.handler.get_property(analyzer, dep, analyzer.factory.string(name));
+    match trap.test_nullish() {
+      Some(true) => ProxyTrap::Absent(analyzer.consumable(trap)),
+      Some(false) => ProxyTrap::Present(trap),
+      None => ProxyTrap::Unknown,
+    }
+  }
+}
+
+fn args_to_array<'a>(
+  analyzer: &mut Analyzer<'a>,
+  dep: Consumable<'a>,
+  args: Entity<'a>,
+) -> Entity<'a> {
+  let (elements, rest, deps) = args.iterate(analyzer, dep);
+  let array = analyzer.new_empty_array();
+  for element in elements {
+    array.push_element(analyzer.factory.computed(element, deps));
+  }
+  if let Some(rest) = rest {
+    array.init_rest(analyzer.factory.computed(rest, deps));
+  }
+  array
+}
+
+impl<'a> EntityFactory<'a> {
+  pub fn proxy(&self, target: Entity<'a>, handler: Entity<'a>) -> Entity<'a> {
+    self.alloc(ProxyEntity { consumed: Cell::new(false), target, handler })
+  }
+}
@@ -1,6 +1,6 @@
 use super::{
   consumed_object, Entity, EntityFactory, EntityTrait, EnumeratedProperties, IteratedElements,
-  TypeofResult,
+  LiteralEntity, TypeofResult,
 };
 use crate::{analyzer::Analyzer, consumable::Consumable, use_consumed_flag};
 use std::cell::{Cell, RefCell};
@@ -27,6 +27,7 @@ impl<'a> EntityTrait<'a> for ReactElementEntity<'a> {
       let copied_props =
         analyzer.new_empty_object(&analyzer.builtins.prototypes.object, Some(group_id));
       copied_props.init_spread(analyzer, analyzer.factory.empty_consumable, props);
+      consume_special_props(analyzer, props);
       tag.jsx(analyzer, copied_props)
     });
   }
@@ -45,6 +46,18 @@ impl<'a> EntityTrait<'a> for ReactElementEntity<'a> {
     dep: Consumable<'a>,
     key: Entity<'a>,
   ) -> Entity<'a> {
+    if !self.consumed.get() {
+      if let Some(LiteralEntity::String(key_str, _)) = key.get_literal(analyzer) {
+        let value = match key_str {
+          "type" => Some(self.tag),
+          "props" => Some(self.props),
+          _ => None,
+        };
+        if let Some(value) = value {
+          return analyzer.factory.computed(value, (dep, key));
+        }
+      }
+    }
     consumed_object::get_property(self, analyzer, dep, key)
   }
 
@@ -155,6 +168,41 @@ impl<'a> EntityTrait<'a> for ReactElementEntity<'a> {
   }
 }
 
+/// `key` and `ref` are used by React itself, so they are kept even if the component never reads
+/// them.
+fn consume_special_props<'a>(analyzer: &mut Analyzer<'a>, props: Entity<'a>) {
+  let key_key = analyzer.get_react_key("key");
+  let key = props.get_property(analyzer, analyzer.factory.empty_consumable, key_key);
+  analyzer.consume(key);
+
+  let key_ref = analyzer.get_react_key("ref");
+  let r#ref = props.get_property(analyzer, analyzer.factory.empty_consumable, key_ref);
+  if r#ref.test_nullish() != Some(true) {
+    let typeof_ref = r#ref.test_typeof();
+    if typeof_ref.contains(TypeofResult::Function) {
+      // Callback ref
+      analyzer.exec_consumed_fn("React_ref", move |analyzer| {
+        r#ref.call(
+          analyzer,
+          analyzer.factory.empty_consumable,
+          analyzer.factory.unknown(),
+          analyzer.factory.unknown(),
+        )
+      });
+    }
+    if typeof_ref.contains(TypeofResult::Object) {
+      // Ref object, e.g. created by `useRef`
+      r#ref.set_property(
+        analyzer,
+        analyzer.factory.empty_consumable,
+        analyzer.factory.string("current"),
+        analyzer.factory.unknown(),
+      );
+    }
+    analyzer.consume(r#ref);
+  }
+}
+
 impl<'a> EntityFactory<'a> {
   pub fn react_element(
     &self,
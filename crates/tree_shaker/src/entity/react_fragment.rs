@@ -0,0 +1,142 @@
+use super::{
+  consumed_object, Entity, EntityFactory, EntityTrait, EnumeratedProperties, IteratedElements,
+  TypeofResult,
+};
+use crate::{analyzer::Analyzer, consumable::Consumable};
+
+/// `React.Fragment` is a symbol at runtime. It behaves like an unknown symbol,
+/// except that it can be used as a JSX tag.
+#[derive(Debug)]
+pub struct ReactFragmentEntity;
+
+impl<'a> EntityTrait<'a> for ReactFragmentEntity {
+  fn consume(&'a self, _analyzer: &mut Analyzer<'a>) {}
+
+  fn unknown_mutate(&'a self, _analyzer: &mut Analyzer<'a>, _dep: Consumable<'a>) {
+    // No effect
+  }
+
+  fn get_property(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Consumable<'a>,
+    key: Entity<'a>,
+  ) -> Entity<'a> {
+    let prototype = &analyzer.builtins.prototypes.symbol;
+    prototype.get_property(analyzer, self, key, dep)
+  }
+
+  fn set_property(
+    &'a self,
+    _analyzer: &mut Analyzer<'a>,
+    _dep: Consumable<'a>,
+    _key: Entity<'a>,
+    _value: Entity<'a>,
+  ) {
+    // No effect
+  }
+
+  fn enumerate_properties(
+    &'a self,
+    _analyzer: &mut Analyzer<'a>,
+    dep: Consumable<'a>,
+  ) -> EnumeratedProperties<'a> {
+    (vec![], dep)
+  }
+
+  fn delete_property(
+    &'a self,
+    _analyzer: &mut Analyzer<'a>,
+    _dep: Consumable<'a>,
+    _key: Entity<'a>,
+  ) {
+    // No effect
+  }
+
+  fn call(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Consumable<'a>,
+    this: Entity<'a>,
+    args: Entity<'a>,
+  ) -> Entity<'a> {
+    analyzer.thrown_builtin_error("Cannot call non-object");
+    consumed_object::call(self, analyzer, dep, this, args)
+  }
+
+  fn construct(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Consumable<'a>,
+    args: Entity<'a>,
+  ) -> Entity<'a> {
+    analyzer.thrown_builtin_error("Cannot construct non-object");
+    consumed_object::construct(self, analyzer, dep, args)
+  }
+
+  fn jsx(&'a self, analyzer: &mut Analyzer<'a>, props: Entity<'a>) -> Entity<'a> {
+    let dep = analyzer.factory.empty_consumable;
+
+    // Only `children` and `key` are used by a fragment
+    let children = props.get_property(analyzer, dep, analyzer.factory.string("children"));
+    let key = props.get_property(analyzer, dep, analyzer.factory.string("key"));
+    analyzer.consume((children, key));
+
+    analyzer.factory.immutable_unknown
+  }
+
+  fn r#await(&'a self, _analyzer: &mut Analyzer<'a>, _dep: Consumable<'a>) -> Entity<'a> {
+    self
+  }
+
+  fn iterate(&'a self, analyzer: &mut Analyzer<'a>, dep: Consumable<'a>) -> IteratedElements<'a> {
+    analyzer.thrown_builtin_error("Cannot iterate non-object");
+    consumed_object::iterate(analyzer, dep)
+  }
+
+  fn get_destructable(&'a self, _analyzer: &Analyzer<'a>, dep: Consumable<'a>) -> Consumable<'a> {
+    dep
+  }
+
+  fn get_typeof(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    analyzer.factory.string("symbol")
+  }
+
+  fn get_to_string(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    analyzer.factory.unknown_string
+  }
+
+  fn get_to_numeric(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    analyzer.factory.unknown()
+  }
+
+  fn get_to_boolean(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    analyzer.factory.r#true
+  }
+
+  fn get_to_property_key(&'a self, _analyzer: &Analyzer<'a>) -> Entity<'a> {
+    self
+  }
+
+  fn get_to_jsx_child(&'a self, analyzer: &Analyzer<'a>) -> Entity<'a> {
+    analyzer.factory.string("")
+  }
+
+  fn test_typeof(&self) -> TypeofResult {
+    TypeofResult::Symbol
+  }
+
+  fn test_truthy(&self) -> Option<bool> {
+    Some(true)
+  }
+
+  fn test_nullish(&self) -> Option<bool> {
+    Some(false)
+  }
+}
+
+impl<'a> EntityFactory<'a> {
+  pub fn react_fragment(&self) -> Entity<'a> {
+    self.alloc(ReactFragmentEntity)
+  }
+}
@@ -1,6 +1,6 @@
 use super::{
   consumed_object, utils::UnionLike, Entity, EntityFactory, EntityTrait, EnumeratedProperties,
-  IteratedElements, LiteralEntity, TypeofResult,
+  IteratedElements, LiteralEntity, ObjectPropertyValue, TypeofResult,
 };
 use crate::{
   analyzer::Analyzer,
@@ -89,6 +89,21 @@ impl<'a, V: UnionLike<'a, Entity<'a>> + Debug + 'a> EntityTrait<'a> for UnionEnt
     })
   }
 
+  fn define_property(
+    &'a self,
+    analyzer: &mut Analyzer<'a>,
+    dep: Consumable<'a>,
+    key: Entity<'a>,
+    value: ObjectPropertyValue<'a>,
+    enumerable: bool,
+  ) {
+    analyzer.exec_indeterminately(|analyzer| {
+      for entity in self.values.iter() {
+        entity.define_property(analyzer, dep, key, value, enumerable);
+      }
+    })
+  }
+
   fn call(
     &'a self,
     analyzer: &mut Analyzer<'a>,
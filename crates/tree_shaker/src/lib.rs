@@ -18,6 +18,7 @@ use utils::{
 };
 
 pub use config::{TreeShakeConfig, TreeShakeJsxPreset};
+pub use mangling::MangleCache;
 pub use tree_shaker::{TreeShakeOptions, TreeShakeReturn, TreeShaker};
 
 pub fn tree_shake(source_text: String, options: TreeShakeOptions) -> TreeShakeReturn {
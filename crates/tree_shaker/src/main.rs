@@ -23,6 +23,10 @@ struct Args {
   #[arg(short, long, default_value_t = String::from("recommended"))]
   preset: String,
 
+  /// A JSON or TOML config file, whose fields override the preset
+  #[arg(short, long)]
+  config: Option<String>,
+
   #[arg(short, long, default_value_t = false)]
   always_inline_literal: bool,
 
@@ -32,8 +36,12 @@ struct Args {
   #[arg(long, default_value_t = false)]
   no_mangle: bool,
 
-  #[arg(short, long, default_value_t = 2)]
-  recursion_depth: usize,
+  #[arg(short, long)]
+  recursion_depth: Option<usize>,
+
+  /// A JSON file of mangled property names, which is read before and updated after the build
+  #[arg(long)]
+  mangle_cache: Option<String>,
 }
 
 fn main() {
@@ -47,6 +55,44 @@ fn main() {
     Ok(content) => content,
   };
 
+  let Some(mut config) = TreeShakeConfig::preset(&args.preset) else {
+    eprintln!("Invalid preset: {}", args.preset);
+    std::process::exit(1);
+  };
+  config = config.with_react_jsx(args.jsx);
+  if let Some(path) = &args.config {
+    config = match config.with_file(path) {
+      Err(why) => {
+        eprintln!("{}", why);
+        std::process::exit(1);
+      }
+      Ok(config) => config,
+    };
+  }
+  if args.always_inline_literal {
+    config = config.with_always_inline_literal(true);
+  }
+  if args.no_mangle {
+    config = config.with_mangling(false);
+  }
+  if let Some(depth) = args.recursion_depth {
+    config = config.with_max_recursion_depth(depth);
+  }
+  let jsx = config.jsx;
+
+  // A missing cache file is created after the build
+  let mangle_cache =
+    match args.mangle_cache.as_ref().and_then(|path| std::fs::read_to_string(path).ok()) {
+      Some(content) => match serde_json::from_str(&content) {
+        Err(why) => {
+          eprintln!("Invalid mangle cache: {}", why);
+          std::process::exit(1);
+        }
+        Ok(cache) => cache,
+      },
+      None => Default::default(),
+    };
+
   let start_time = std::time::Instant::now();
 
   let minify_options = MinifierOptions {
@@ -57,45 +103,37 @@ fn main() {
   let copied = tree_shake(
     source.clone(),
     TreeShakeOptions {
-      config: TreeShakeConfig::disabled().with_react_jsx(args.jsx),
+      config: TreeShakeConfig::disabled().with_jsx(jsx),
       minify_options: None,
       codegen_options: CodegenOptions::default(),
+      mangle_cache: Default::default(),
     },
   );
   let minified = tree_shake(
     source.clone(),
     TreeShakeOptions {
-      config: TreeShakeConfig::disabled().with_react_jsx(args.jsx),
+      config: TreeShakeConfig::disabled().with_jsx(jsx),
       minify_options: Some(minify_options),
       codegen_options: CodegenOptions { minify: true, comments: false, ..Default::default() },
+      mangle_cache: Default::default(),
     },
   );
   let shaken = tree_shake(
     source.clone(),
     TreeShakeOptions {
-      config: match args.preset.as_str() {
-        "safest" => TreeShakeConfig::safest(),
-        "recommended" => TreeShakeConfig::recommended(),
-        "smallest" => TreeShakeConfig::smallest(),
-        _ => {
-          eprintln!("Invalid preset: {}", args.preset);
-          std::process::exit(1);
-        }
-      }
-      .with_react_jsx(args.jsx)
-      .with_always_inline_literal(args.always_inline_literal)
-      .with_mangling(!args.no_mangle)
-      .with_max_recursion_depth(args.recursion_depth),
+      config,
       minify_options: None,
       codegen_options: CodegenOptions::default(),
+      mangle_cache,
     },
   );
   let shaken_minified = tree_shake(
     shaken.codegen_return.code.clone(),
     TreeShakeOptions {
-      config: TreeShakeConfig::disabled().with_react_jsx(args.jsx),
+      config: TreeShakeConfig::disabled().with_jsx(jsx),
       minify_options: Some(minify_options),
       codegen_options: CodegenOptions { minify: true, comments: false, ..Default::default() },
+      mangle_cache: Default::default(),
     },
   );
 
@@ -105,6 +143,14 @@ fn main() {
     eprintln!("{}", diagnostic);
   }
 
+  if let Some(path) = &args.mangle_cache {
+    let content = serde_json::to_string_pretty(&shaken.mangle_cache).unwrap();
+    if let Err(why) = std::fs::write(path, content) {
+      eprintln!("Couldn't write to {}: {}", path, why);
+      std::process::exit(1);
+    }
+  }
+
   eprintln!("Completed in {:?}", elapsed);
   eprintln!("Original: {}B", copied.codegen_return.code.len());
   eprintln!("Minified: {}B", minified.codegen_return.code.len());
@@ -1,7 +1,9 @@
 use super::{utils::get_mangled_name, MangleAtom};
+use crate::TreeShakeConfig;
 use oxc::allocator::Allocator;
 use oxc_index::IndexVec;
-use rustc_hash::FxHashSet;
+use regex::Regex;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 oxc_index::define_index_type! {
   pub struct IdentityGroupId = u32;
@@ -20,35 +22,83 @@ pub enum AtomState<'a> {
   NonMangable,
 }
 
+/// Decides which property names may be mangled, from `mangle_reserved`, `mangle_include` and
+/// `mangle_exclude` in the config.
+pub struct MangleFilter {
+  reserved: FxHashSet<String>,
+  include: Option<Regex>,
+  exclude: Option<Regex>,
+}
+
+impl MangleFilter {
+  pub fn new(config: &TreeShakeConfig) -> Self {
+    Self {
+      reserved: config.mangle_reserved.iter().cloned().collect(),
+      include: config.mangle_include.clone(),
+      exclude: config.mangle_exclude.clone(),
+    }
+  }
+
+  pub fn is_mangable(&self, name: &str) -> bool {
+    !self.reserved.contains(name)
+      && self.include.as_ref().map_or(true, |regex| regex.is_match(name))
+      && !self.exclude.as_ref().is_some_and(|regex| regex.is_match(name))
+  }
+}
+
+/// Maps original property names to their mangled names, so that they are stable across builds.
+/// Different originals may share a mangled name, which is only reused for an atom if no other
+/// atom in its uniqueness groups already has it.
+pub type MangleCache = FxHashMap<String, String>;
+
 pub struct Mangler<'a> {
   pub enabled: bool,
+  pub filter: MangleFilter,
 
   pub allocator: &'a Allocator,
 
   pub atoms: IndexVec<MangleAtom, AtomState<'a>>,
+  /// The original names of the atoms
+  pub names: IndexVec<MangleAtom, &'a str>,
 
   /// (atoms, resolved_name)[]
   pub identity_groups: IndexVec<IdentityGroupId, (Vec<MangleAtom>, Option<&'a str>)>,
   /// (atoms, used_names)[]
   pub uniqueness_groups: IndexVec<UniquenessGroupId, (Vec<MangleAtom>, usize)>,
+
+  /// The input name cache
+  pub cache: MangleCache,
+  /// Mangled names in the cache, which are not generated for other names
+  pub cached_names: FxHashSet<String>,
 }
 
 impl<'a> Mangler<'a> {
-  pub fn new(enabled: bool, allocator: &'a Allocator) -> Self {
+  pub fn new(config: &TreeShakeConfig, cache: MangleCache, allocator: &'a Allocator) -> Self {
     Self {
-      enabled,
+      enabled: config.mangling,
+      filter: MangleFilter::new(config),
       allocator,
       atoms: IndexVec::new(),
+      names: IndexVec::new(),
       identity_groups: IndexVec::new(),
       uniqueness_groups: IndexVec::new(),
+      cached_names: cache.values().cloned().collect(),
+      cache,
     }
   }
 
-  pub fn new_atom(&mut self) -> MangleAtom {
-    self.atoms.push(AtomState::Constrained(None, FxHashSet::default()))
+  /// Names rejected by the filter get constant atoms, which are kept as is.
+  pub fn new_atom(&mut self, name: &'a str) -> MangleAtom {
+    if self.filter.is_mangable(name) {
+      self.names.push(name);
+      self.atoms.push(AtomState::Constrained(None, FxHashSet::default()))
+    } else {
+      self.new_constant_atom(name)
+    }
   }
 
   pub fn new_constant_atom(&mut self, str: &'a str) -> MangleAtom {
+    self.names.push(str);
     self.atoms.push(AtomState::Constant(str))
   }
 
@@ -62,15 +112,16 @@ impl<'a> Mangler<'a> {
           self.resolve_identity_group(*identity_group)
         } else if uniqueness_groups.is_empty() {
           // This is quite weird, isn't it?
-          "a"
+          self.cache.get(self.names[atom]).map_or("a", |name| self.allocator.alloc_str(name))
         } else {
           let mut n =
             uniqueness_groups.iter().map(|&index| self.uniqueness_groups[index].1).max().unwrap();
-          let name = get_mangled_name(&mut n);
+          let taken = self.resolved_names_in(uniqueness_groups.iter().copied());
+          let name = self.pick_name([self.names[atom]].into_iter(), &taken, &mut n);
           for &index in uniqueness_groups {
             self.uniqueness_groups[index].1 = n;
           }
-          self.allocator.alloc(name)
+          name
         };
         self.atoms[atom] = AtomState::Constant(resolved);
         Some(resolved)
@@ -81,28 +132,90 @@ impl<'a> Mangler<'a> {
   }
 
   fn resolve_identity_group(&mut self, id: IdentityGroupId) -> &'a str {
-    let Mangler { identity_groups, uniqueness_groups, atoms: constraints, .. } = self;
-    let (atoms, resolved_name) = &mut identity_groups[id];
-    resolved_name.get_or_insert_with(|| {
-      let mut n = 0;
-      let mut related_uniq_groups = vec![];
-      for atom in atoms {
-        match &constraints[*atom] {
-          AtomState::Constrained(_, uniq_groups) => {
-            for index in uniq_groups {
-              related_uniq_groups.push(*index);
-              n = n.max(uniqueness_groups[*index].1);
+    if let Some(resolved_name) = self.identity_groups[id].1 {
+      return resolved_name;
+    }
+
+    let mut n = 0;
+    let mut related_uniq_groups = vec![];
+    for atom in &self.identity_groups[id].0 {
+      match &self.atoms[*atom] {
+        AtomState::Constrained(_, uniq_groups) => {
+          for index in uniq_groups {
+            related_uniq_groups.push(*index);
+            n = n.max(self.uniqueness_groups[*index].1);
+          }
+        }
+        AtomState::Constant(s) => {
+          let s = *s;
+          self.identity_groups[id].1 = Some(s);
+          return s;
+        }
+        AtomState::NonMangable => unreachable!(),
+      }
+    }
+    let taken = self.resolved_names_in(related_uniq_groups.iter().copied());
+    let originals = self.identity_groups[id].0.iter().map(|atom| self.names[*atom]);
+    let name = self.pick_name(originals, &taken, &mut n);
+    for index in related_uniq_groups {
+      self.uniqueness_groups[index].1 = n;
+    }
+    self.identity_groups[id].1 = Some(name);
+    name
+  }
+
+  /// Prefers the cached name of the original names, then generates a new one.
+  fn pick_name(
+    &self,
+    mut originals: impl Iterator<Item = &'a str>,
+    taken: &FxHashSet<&'a str>,
+    n: &mut usize,
+  ) -> &'a str {
+    let cached = originals
+      .find_map(|original| self.cache.get(original).filter(|name| !taken.contains(name.as_str())));
+    if let Some(cached) = cached {
+      return self.allocator.alloc_str(cached);
+    }
+    let name = get_mangled_name(n, |name| taken.contains(name) || self.cached_names.contains(name));
+    self.allocator.alloc_str(&name)
+  }
+
+  /// Names already resolved in the uniqueness groups, including the atoms whose identity group is
+  /// resolved. A name must not be reused if any member of the related groups has it.
+  fn resolved_names_in(
+    &self,
+    groups: impl Iterator<Item = UniquenessGroupId>,
+  ) -> FxHashSet<&'a str> {
+    let mut names = FxHashSet::default();
+    for group in groups {
+      for atom in &self.uniqueness_groups[group].0 {
+        match &self.atoms[*atom] {
+          AtomState::Constant(name) => {
+            names.insert(*name);
+          }
+          AtomState::Constrained(Some(identity_group), _) => {
+            if let Some(name) = self.identity_groups[*identity_group].1 {
+              names.insert(name);
             }
           }
-          AtomState::Constant(s) => return *s,
-          AtomState::NonMangable => unreachable!(),
+          _ => {}
         }
       }
-      let name = get_mangled_name(&mut n);
-      for index in related_uniq_groups {
-        uniqueness_groups[index].1 = n;
+    }
+    names
+  }
+
+  /// The input cache, updated with the names resolved in this build.
+  pub fn into_cache(self) -> MangleCache {
+    let mut cache = self.cache;
+    for (atom, state) in self.atoms.iter_enumerated() {
+      if let AtomState::Constant(name) = state {
+        let original = self.names[atom];
+        if *name != original {
+          cache.entry(original.to_string()).or_insert_with(|| name.to_string());
+        }
       }
-      self.allocator.alloc(name)
-    })
+    }
+    cache
   }
 }
@@ -39,13 +39,14 @@ fn generate_name(n: usize) -> CompactStr {
   CompactStr::new(&ret)
 }
 
-pub fn get_mangled_name(count: &mut usize) -> CompactStr {
+/// `is_taken` tells whether a name is already used in the related uniqueness groups.
+pub fn get_mangled_name(count: &mut usize, is_taken: impl Fn(&str) -> bool) -> CompactStr {
   loop {
     let name = generate_name(*count);
     *count += 1;
     // Do not mangle keywords and unresolved references
     let n = name.as_str();
-    if !is_keyword(n) && !is_special_name(n)
+    if !is_keyword(n) && !is_special_name(n) && !is_taken(n)
     // && !root_unresolved_references.contains_key(n)
     // && (self.options.top_level || !root_bindings.contains_key(n))
     {
@@ -32,27 +32,35 @@ impl<'a> Analyzer<'a> {
     args: Entity<'a>,
     consume: bool,
   ) -> Entity<'a> {
-    self.push_call_scope(
-      callee,
-      call_dep,
-      variable_scopes.as_ref().clone(),
-      node.r#async,
-      false,
-      consume,
-    );
+    let runner = move |analyzer: &mut Analyzer<'a>| {
+      analyzer.push_call_scope(
+        callee,
+        call_dep,
+        variable_scopes.as_ref().clone(),
+        node.r#async,
+        false,
+        consume,
+      );
 
-    self.exec_formal_parameters(&node.params, args, DeclarationKind::ArrowFunctionParameter);
-    if node.expression {
-      self.exec_function_expression_body(&node.body);
-    } else {
-      self.exec_function_body(&node.body);
-    }
+      analyzer.exec_formal_parameters(&node.params, args, DeclarationKind::ArrowFunctionParameter);
+      if node.expression {
+        analyzer.exec_function_expression_body(&node.body);
+      } else {
+        analyzer.exec_function_body(&node.body);
+      }
 
-    if consume {
-      self.consume_return_values();
-    }
+      if consume {
+        analyzer.consume_return_values();
+      }
 
-    self.pop_call_scope()
+      analyzer.pop_call_scope()
+    };
+
+    if node.r#async {
+      self.exec_async_fn(runner)
+    } else {
+      runner(self)
+    }
   }
 }
 
@@ -1,9 +1,13 @@
 use crate::{analyzer::Analyzer, entity::Entity, transformer::Transformer};
-use oxc::ast::ast::{AwaitExpression, Expression};
+use oxc::{
+  ast::ast::{AwaitExpression, Expression},
+  span::GetSpan,
+};
 
 impl<'a> Analyzer<'a> {
   pub fn exec_await_expression(&mut self, node: &'a AwaitExpression<'a>) -> Entity<'a> {
     let call_scope = self.call_scope_mut();
+    call_scope.has_await = true;
     if !call_scope.is_async {
       self.add_diagnostic("SyntaxError: await is only valid in async functions");
     }
@@ -19,11 +23,14 @@ impl<'a> Transformer<'a> {
   pub fn transform_await_expression(
     &self,
     node: &'a AwaitExpression<'a>,
-    _need_val: bool,
+    need_val: bool,
   ) -> Option<Expression<'a>> {
     let AwaitExpression { span, argument } = node;
 
-    let argument = self.transform_expression(argument, true).unwrap();
+    // The `await` itself is always kept, but the awaited value may be unused
+    let argument = self
+      .transform_expression(argument, need_val)
+      .unwrap_or_else(|| self.build_unused_expression(argument.span()));
     Some(self.ast_builder.expression_await(*span, argument))
   }
 }
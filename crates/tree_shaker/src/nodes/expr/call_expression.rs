@@ -24,7 +24,12 @@ impl<'a> Analyzer<'a> {
     &mut self,
     node: &'a CallExpression,
   ) -> Result<(usize, Entity<'a>, Option<Entity<'a>>), Entity<'a>> {
-    let (mut scope_count, callee, mut undefined, this) = self.exec_callee(&node.callee)?;
+    let pure = self.is_manual_pure_callee(&node.callee);
+    let (mut scope_count, callee, mut undefined, this) = if pure {
+      (0, self.exec_manual_pure_callee(&node.callee), None, self.factory.unknown())
+    } else {
+      self.exec_callee(&node.callee)?
+    };
 
     let dep_id = AstKind2::CallExpression(node);
 
@@ -52,7 +57,11 @@ impl<'a> Analyzer<'a> {
 
     let args = self.exec_arguments(&node.arguments);
 
-    let ret_val = callee.call(self, self.consumable(dep_id), this, args);
+    let ret_val = if pure {
+      self.factory.computed_unknown(self.consumable((callee, dep_id, args)))
+    } else {
+      callee.call(self, self.consumable(dep_id), this, args)
+    };
 
     Ok((scope_count, ret_val, undefined))
   }
@@ -85,6 +94,18 @@ impl<'a> Transformer<'a> {
 
     let need_call = need_val || self.is_referred(dep_id);
 
+    if self.is_manual_pure_callee(callee) {
+      // The path of the callee is not analyzed
+      return Ok(if need_call {
+        let callee = self.clone_node(callee);
+        let arguments = self.transform_arguments_need_call(arguments);
+        Some(self.ast_builder.expression_call(*span, callee, NONE, arguments, need_optional))
+      } else {
+        let args_effect = self.transform_arguments_no_call(arguments);
+        build_effect!(&self.ast_builder, *span, args_effect)
+      });
+    }
+
     if !need_call {
       let callee = self.transform_expression_in_chain(callee, need_optional)?;
       let args_effect = self.transform_arguments_no_call(arguments);
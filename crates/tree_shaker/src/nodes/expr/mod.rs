@@ -46,6 +46,7 @@ impl<'a> Analyzer<'a> {
   pub fn exec_expression(&mut self, node: &'a Expression<'a>) -> Entity<'a> {
     self.push_span(node);
     let entity = match node {
+      _ if self.is_defined(node) => self.exec_define(node),
       match_member_expression!(Expression) => {
         self.exec_member_expression_read(node.to_member_expression(), false).0
       }
@@ -106,6 +107,10 @@ impl<'a> Transformer<'a> {
     let data = self.get_data::<Data>(AstKind2::Expression(node));
 
     let span = node.span();
+    if let Some(literal) = self.get_define(node) {
+      return need_val.then(|| literal.build_expr(self, span, None));
+    }
+
     let literal = need_val.then(|| data.collector.build_expr(self, span)).flatten();
     let need_val = need_val && literal.is_none();
 
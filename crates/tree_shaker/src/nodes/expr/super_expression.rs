@@ -3,7 +3,7 @@ use oxc::ast::ast::{Expression, Super};
 
 impl<'a> Analyzer<'a> {
   pub fn exec_super(&mut self, _node: &'a Super) -> Entity<'a> {
-    self.factory.unknown()
+    self.get_super_class().unwrap_or_else(|| self.factory.unknown())
   }
 }
 
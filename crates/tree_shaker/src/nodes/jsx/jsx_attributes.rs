@@ -69,7 +69,17 @@ impl<'a> Transformer<'a> {
         JSXAttributeItem::Attribute(node) => {
           let JSXAttribute { span, name, value } = node.as_ref();
 
-          if let Some(value) = self.transform_jsx_attribute_value_as_item(value, is_referred) {
+          if value.is_none() {
+            // `<A b />` is `<A b={true} />`
+            if is_referred {
+              transformed.push(self.ast_builder.jsx_attribute_item_jsx_attribute(
+                *span,
+                self.transform_jsx_attribute_name_need_val(name),
+                None,
+              ));
+            }
+          } else if let Some(value) = self.transform_jsx_attribute_value_as_item(value, is_referred)
+          {
             transformed.push(self.ast_builder.jsx_attribute_item_jsx_attribute(
               *span,
               self.transform_jsx_attribute_name_need_val(name),
@@ -12,11 +12,21 @@ impl<'a> Analyzer<'a> {
     let tag = self.exec_jsx_element_name(&node.opening_element.name);
     let attributes = self.exec_jsx_attributes(&node.opening_element);
     let children = self.exec_jsx_children(&node.children);
-    let key_children = *self.builtins.react_data.key_children.get_or_insert_with(|| {
-      self.factory.mangable_string("children", self.mangler.new_constant_atom("children"))
-    });
+    let key_children = self.get_react_key("children");
     attributes.init_property(self, PropertyKind::Init, key_children, children, true);
-    self.factory.react_element(tag, attributes)
+    let element = self.factory.react_element(tag, attributes);
+    match self.read_jsx_pragma_binding(self.jsx_pragma.factory) {
+      Some(factory) => self.factory.computed(element, factory),
+      None => element,
+    }
+  }
+
+  /// Reads the binding referenced by a pragma like `/** @jsx h */`. The compiled code calls it, so
+  /// it must be kept as long as the JSX is kept.
+  pub fn read_jsx_pragma_binding(&mut self, pragma: Option<&'a str>) -> Option<Entity<'a>> {
+    let name = pragma?.split('.').next()?;
+    let symbol = self.semantic.scopes().get_root_binding(name)?;
+    self.read_symbol(symbol)
   }
 }
 
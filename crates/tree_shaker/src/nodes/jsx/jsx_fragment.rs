@@ -7,7 +7,16 @@ use oxc::{
 impl<'a> Analyzer<'a> {
   pub fn exec_jsx_fragment(&mut self, node: &'a JSXFragment<'a>) -> Entity<'a> {
     // already computed unknown
-    self.exec_jsx_children(&node.children)
+    let children = self.exec_jsx_children(&node.children);
+    let pragma = self.jsx_pragma;
+    match (
+      self.read_jsx_pragma_binding(pragma.factory),
+      self.read_jsx_pragma_binding(pragma.fragment),
+    ) {
+      (Some(factory), Some(fragment)) => self.factory.computed(children, (factory, fragment)),
+      (Some(binding), None) | (None, Some(binding)) => self.factory.computed(children, binding),
+      (None, None) => children,
+    }
   }
 }
 
@@ -1,9 +1,13 @@
-use crate::{analyzer::Analyzer, ast::AstKind2, entity::Entity, transformer::Transformer};
+use crate::{
+  analyzer::Analyzer, ast::AstKind2, entity::Entity, transformer::Transformer,
+  utils::ast::get_static_path, TreeShakeConfig,
+};
 use oxc::{
   ast::{
     ast::{ChainElement, Expression, MemberExpression},
     match_member_expression,
   },
+  semantic::SymbolTable,
   span::{GetSpan, SPAN},
 };
 
@@ -26,7 +30,41 @@ fn unwrap_to_member_expression<'a>(
   }
 }
 
+/// Whether the callee matches `config.manual_pure_functions`. Only globals and imports count.
+fn is_manual_pure_callee<'a>(
+  config: &TreeShakeConfig,
+  symbols: &SymbolTable,
+  node: &'a Expression<'a>,
+) -> bool {
+  if config.manual_pure_functions.is_empty() {
+    return false;
+  }
+  let Some((path, root)) = get_static_path(node) else {
+    return false;
+  };
+  if let Some(symbol) = symbols.get_reference(root.reference_id()).symbol_id() {
+    if !symbols.get_flags(symbol).is_import() {
+      return false;
+    }
+  }
+  config.manual_pure_functions.iter().any(|pure| {
+    path.strip_prefix(pure.as_str()).is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+  })
+}
+
 impl<'a> Analyzer<'a> {
+  pub fn is_manual_pure_callee(&self, node: &'a Expression<'a>) -> bool {
+    is_manual_pure_callee(self.config, self.semantic.symbols(), node)
+  }
+
+  /// The path of a pure callee is never read, except for the imported binding.
+  pub fn exec_manual_pure_callee(&mut self, node: &'a Expression<'a>) -> Entity<'a> {
+    let (_, root) = get_static_path(node).unwrap();
+    let reference = self.semantic.symbols().get_reference(root.reference_id());
+    let root = reference.symbol_id().map(|_| self.exec_identifier_reference_read(root));
+    self.factory.computed_unknown((root, AstKind2::Callee(node)))
+  }
+
   /// Returns: Ok((scope_count, callee, undefined, this)) or Err(forwarded_undefined) for should not call due to ?. operator
   pub fn exec_callee(
     &mut self,
@@ -61,6 +99,10 @@ impl<'a> Analyzer<'a> {
 }
 
 impl<'a> Transformer<'a> {
+  pub fn is_manual_pure_callee(&self, node: &'a Expression<'a>) -> bool {
+    is_manual_pure_callee(self.config, self.semantic.symbols(), node)
+  }
+
   pub fn transform_callee(
     &self,
     node: &'a Expression<'a>,
@@ -1,8 +1,8 @@
 use crate::{
   analyzer::Analyzer,
   ast::{AstKind2, DeclarationKind},
-  consumable::ConsumableTrait,
-  entity::{ClassEntity, Entity},
+  consumable::{Consumable, ConsumableTrait},
+  entity::{ClassEntity, Entity, ObjectEntity, ObjectPropertyValue, TypeofResult},
   transformer::Transformer,
   utils::CalleeNode,
 };
@@ -35,6 +35,7 @@ impl<'a> Analyzer<'a> {
         if node.r#static {
           let key = keys[index].unwrap();
           let value = self.exec_function(&node.value);
+          let value = self.factory.computed(value, (AstKind2::MethodDefinition(node), key));
           let kind = match node.kind {
             MethodDefinitionKind::Constructor => unreachable!(),
             MethodDefinitionKind::Method => PropertyKind::Init,
@@ -138,7 +139,7 @@ impl<'a> Analyzer<'a> {
       if let ClassElement::MethodDefinition(node) = element {
         if !node.r#static {
           let value = self.exec_function(&node.value);
-          self.consume(value);
+          self.consume((AstKind2::MethodDefinition(node), value));
         }
       }
     }
@@ -166,7 +167,7 @@ impl<'a> Analyzer<'a> {
           if !node.r#static {
             if let Some(value) = &node.value {
               let value = analyzer.exec_expression(value);
-              analyzer.consume(value);
+              analyzer.consume((AstKind2::PropertyDefinition(node), value));
             }
           }
         }
@@ -181,6 +182,113 @@ impl<'a> Analyzer<'a> {
       self.pop_variable_scope();
     }
   }
+
+  /// Constructs an instance of a class without super class. Methods and accessors are defined as
+  /// non-enumerable own properties of the instance, so that they are tracked like object literals.
+  pub fn construct_class_instance(
+    &mut self,
+    class: &'a ClassEntity<'a>,
+    dep: Consumable<'a>,
+    args: Entity<'a>,
+  ) -> Entity<'a> {
+    let dep = self.consumable((AstKind2::Class(class.node), dep));
+    let instance = self.new_empty_object(&self.builtins.prototypes.object, None);
+    let returned = self.init_class_instance(class, dep, instance, None, args);
+
+    let value = match returned {
+      Some(returned)
+        if returned.test_typeof().intersects(TypeofResult::Object | TypeofResult::Function) =>
+      {
+        self.factory.union(vec![instance, returned])
+      }
+      _ => instance,
+    };
+    self.factory.computed(value, dep)
+  }
+
+  /// Defines the methods and fields of a class on `instance`, and then calls the constructor.
+  /// `super_class` is the value of `super` inside the class body. Returns the value returned by the
+  /// constructor.
+  pub fn init_class_instance(
+    &mut self,
+    class: &'a ClassEntity<'a>,
+    dep: Consumable<'a>,
+    instance: &'a ObjectEntity<'a>,
+    super_class: Option<Entity<'a>>,
+    args: Entity<'a>,
+  ) -> Option<Entity<'a>> {
+    let node = class.node;
+
+    self.push_call_scope(
+      self.new_callee_info(CalleeNode::ClassConstructor(node)),
+      dep,
+      class.variable_scope_stack.as_ref().clone(),
+      false,
+      false,
+      false,
+    );
+
+    let variable_scope = self.variable_scope_mut();
+    variable_scope.this = Some(instance);
+    variable_scope.arguments = Some((args, vec![]));
+    variable_scope.super_class = super_class;
+
+    if let Some(id) = &node.id {
+      self.declare_binding_identifier(id, false, DeclarationKind::NamedFunctionInBody);
+      self.init_binding_identifier(id, Some(class));
+    }
+
+    // Methods and accessors
+    let mut constructor = None;
+    for (index, element) in node.body.body.iter().enumerate() {
+      if let ClassElement::MethodDefinition(node) = element {
+        if !node.r#static {
+          let key = class.keys[index].unwrap();
+          let value = self.exec_function(&node.value);
+          let value = self.factory.computed(value, (AstKind2::MethodDefinition(node), key));
+          let kind = match node.kind {
+            MethodDefinitionKind::Constructor => {
+              constructor = Some(value);
+              continue;
+            }
+            MethodDefinitionKind::Method => PropertyKind::Init,
+            MethodDefinitionKind::Get => PropertyKind::Get,
+            MethodDefinitionKind::Set => PropertyKind::Set,
+          };
+          instance.init_property(self, kind, key, value, true);
+        }
+      }
+    }
+    for property in instance.string_keyed.borrow_mut().values_mut() {
+      property.non_enumerable.push(self.factory.empty_consumable);
+    }
+
+    // Fields
+    for (index, element) in node.body.body.iter().enumerate() {
+      if let ClassElement::PropertyDefinition(node) = element {
+        if !node.r#static {
+          let value = match &node.value {
+            Some(value) => self.exec_expression(value),
+            None => self.factory.undefined,
+          };
+          let value = self.factory.computed(value, AstKind2::PropertyDefinition(node));
+          instance.define_property(
+            self,
+            self.factory.empty_consumable,
+            class.keys[index].unwrap(),
+            ObjectPropertyValue::Field(value, false),
+            true,
+          );
+        }
+      }
+    }
+
+    let returned = constructor.map(|constructor| constructor.call(self, dep, instance, args));
+
+    self.pop_call_scope();
+
+    returned
+  }
 }
 
 impl<'a> Transformer<'a> {
@@ -223,7 +331,13 @@ impl<'a> Transformer<'a> {
         let mut transformed_body = self.ast_builder.vec();
 
         for element in body {
-          if ever_constructed || element.r#static() {
+          let need_element = if let ClassElement::MethodDefinition(node) = element {
+            // Unused methods and accessors are removed
+            self.is_referred(AstKind2::MethodDefinition(node))
+          } else {
+            ever_constructed || element.r#static()
+          };
+          if need_element {
             if let Some(element) = match element {
               ClassElement::StaticBlock(node) => {
                 self.transform_static_block(node).map(ClassElement::StaticBlock)
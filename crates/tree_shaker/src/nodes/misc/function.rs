@@ -81,12 +81,14 @@ impl<'a> Analyzer<'a> {
         analyzer.pop_call_scope()
       });
 
-    if node.r#async || node.generator {
+    if node.generator {
       // Too complex to analyze the control flow, thus run exhaustively
       self.exec_async_or_generator_fn(move |analyzer| {
         runner(analyzer).consume(analyzer);
       });
       self.factory.unknown()
+    } else if node.r#async {
+      self.exec_async_fn(runner)
     } else {
       runner(self)
     }
@@ -5,7 +5,7 @@ use crate::{
 impl<'a> Analyzer<'a> {
   pub fn exec_mangable_static_string(&mut self, key: impl Into<DepId>, str: &'a str) -> Entity<'a> {
     let atom = self.load_data::<Option<MangleAtom>>(key.into());
-    self.factory.mangable_string(str, *atom.get_or_insert_with(|| self.mangler.new_atom()))
+    self.factory.mangable_string(str, *atom.get_or_insert_with(|| self.mangler.new_atom(str)))
   }
 }
 
@@ -1,4 +1,4 @@
-use crate::transformer::Transformer;
+use crate::{ast::AstKind2, transformer::Transformer};
 use oxc::ast::{
   ast::{ClassElement, PropertyDefinition},
   NONE,
@@ -12,7 +12,8 @@ impl<'a> Transformer<'a> {
     let PropertyDefinition { r#type, span, decorators, key, value, computed, r#static, .. } = node;
 
     let key = self.transform_property_key(key, true).unwrap();
-    let value = value.as_ref().map(|node| self.transform_expression(node, true).unwrap());
+    let need_val = self.is_referred(AstKind2::PropertyDefinition(node));
+    let value = value.as_ref().and_then(|value| self.transform_expression(value, need_val));
 
     Some(self.ast_builder.class_element_property_definition(
       *span,
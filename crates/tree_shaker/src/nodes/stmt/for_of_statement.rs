@@ -8,6 +8,7 @@ impl<'a> Analyzer<'a> {
   pub fn exec_for_of_statement(&mut self, node: &'a ForOfStatement<'a>) {
     let right = self.exec_expression(&node.right);
     let right = if node.r#await {
+      self.call_scope_mut().has_await = true;
       right.consume(self);
       self.refer_dep(AstKind2::ForOfStatement(node));
       self.factory.immutable_unknown
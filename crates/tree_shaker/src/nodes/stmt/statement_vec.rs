@@ -1,8 +1,14 @@
-use crate::{analyzer::Analyzer, transformer::Transformer, utils::StatementVecData};
+use crate::{analyzer::Analyzer, ast::AstKind2, transformer::Transformer, utils::StatementVecData};
 use oxc::{
   allocator::Vec,
-  ast::{ast::Statement, match_declaration, match_module_declaration},
+  ast::{
+    ast::{BindingIdentifier, Expression, IdentifierReference, Statement},
+    match_declaration, match_module_declaration, Visit,
+  },
+  semantic::{SymbolId, SymbolTable},
+  span::GetSpan,
 };
+use oxc_ecmascript::BoundNames;
 
 impl<'a> Analyzer<'a> {
   pub fn exec_statement_vec(
@@ -10,6 +16,22 @@ impl<'a> Analyzer<'a> {
     data: &mut StatementVecData,
     statements: &'a Vec<'a, Statement<'a>>,
   ) {
+    let mut ignoring = false;
+    for (index, statement) in statements.iter().enumerate() {
+      let span = statement.span();
+      if ignoring && self.has_ignore_end_directive(span) {
+        ignoring = false;
+      }
+      if !ignoring && self.has_ignore_start_directive(span) {
+        ignoring = true;
+      }
+      if ignoring {
+        data.preserved_stmts.insert(index);
+      } else if self.has_keep_directive(span) {
+        data.kept_stmts.insert(index);
+      }
+    }
+
     for statement in statements {
       self.declare_statement(statement);
     }
@@ -19,7 +41,13 @@ impl<'a> Analyzer<'a> {
       if self.cf_scope().must_exited() {
         break;
       }
-      self.init_statement(statement);
+      if data.preserved_stmts.contains(&index) {
+        self.exec_preserved_statement(statement);
+      } else if data.kept_stmts.contains(&index) {
+        self.exec_kept_statement(statement);
+      } else {
+        self.init_statement(statement);
+      }
       last_stmt = Some(index);
     }
     if let Some(last_stmt) = last_stmt {
@@ -29,6 +57,63 @@ impl<'a> Analyzer<'a> {
       };
     }
   }
+
+  /// The statement is transformed as usual, but its value and declarations are consumed, so that
+  /// it is never removed.
+  fn exec_kept_statement(&mut self, node: &'a Statement<'a>) {
+    if let Statement::ExpressionStatement(node) = node {
+      self.push_span(node.as_ref());
+      let value = self.exec_expression(&node.expression);
+      self.consume(value);
+      // A kept call is emitted even without side effects, so its callee must be kept as well
+      let callee = match node.expression.without_parentheses() {
+        Expression::CallExpression(node) => {
+          self.refer_dep(AstKind2::CallExpression(node));
+          Some(&node.callee)
+        }
+        Expression::NewExpression(node) => {
+          self.refer_dep(AstKind2::NewExpression(node));
+          Some(&node.callee)
+        }
+        _ => None,
+      };
+      if let Some(callee) = callee {
+        let mut collector = SymbolCollector::new(self.semantic.symbols());
+        collector.visit_expression(callee);
+        for symbol in collector.referenced {
+          self.consume_symbol(symbol);
+        }
+      }
+      self.pop_span();
+    } else {
+      self.init_statement(node);
+    }
+
+    let mut declared = vec![];
+    let mut collect = |ident: &BindingIdentifier<'a>| declared.push(ident.symbol_id());
+    match node {
+      match_declaration!(Statement) => node.to_declaration().bound_names(&mut collect),
+      match_module_declaration!(Statement) => {
+        node.to_module_declaration().bound_names(&mut collect)
+      }
+      _ => {}
+    }
+    for symbol in declared {
+      self.consume_symbol(symbol);
+    }
+  }
+
+  /// The statement is emitted as is, so everything it references or declares is consumed.
+  fn exec_preserved_statement(&mut self, node: &'a Statement<'a>) {
+    self.init_statement(node);
+    self.refer_to_global();
+
+    let mut collector = SymbolCollector::new(self.semantic.symbols());
+    collector.visit_statement(node);
+    for symbol in collector.referenced.into_iter().chain(collector.declared) {
+      self.consume_symbol(symbol);
+    }
+  }
 }
 
 impl<'a> Transformer<'a> {
@@ -46,7 +131,13 @@ impl<'a> Transformer<'a> {
     let mut exited = false;
     for (index, statement) in statements.iter().enumerate() {
       if !exited {
-        if let Some(statement) = self.transform_statement(statement) {
+        if data.preserved_stmts.contains(&index) {
+          result.push(self.transform_preserved_statement(statement));
+        } else if data.kept_stmts.contains(&index) {
+          if let Some(statement) = self.transform_kept_statement(statement) {
+            result.push(statement);
+          }
+        } else if let Some(statement) = self.transform_statement(statement) {
           result.push(statement);
         }
       } else if is_declaration(statement) {
@@ -64,6 +155,29 @@ impl<'a> Transformer<'a> {
 
     result
   }
+
+  fn transform_kept_statement(&self, node: &'a Statement<'a>) -> Option<Statement<'a>> {
+    if let Statement::ExpressionStatement(node) = node {
+      // The value is consumed, so it is kept even without side effects
+      let expression = self
+        .transform_expression(&node.expression, false)
+        .or_else(|| self.transform_expression(&node.expression, true))
+        .unwrap();
+      Some(self.ast_builder.statement_expression(node.span, expression))
+    } else {
+      self.transform_statement(node)
+    }
+  }
+
+  fn transform_preserved_statement(&self, node: &'a Statement<'a>) -> Statement<'a> {
+    let mut collector = SymbolCollector::new(self.semantic.symbols());
+    collector.visit_statement(node);
+    for symbol in collector.declared {
+      self.update_var_decl_state(symbol, true);
+    }
+
+    self.clone_node(node)
+  }
 }
 
 fn is_declaration<'a>(statement: &'a Statement<'a>) -> bool {
@@ -74,3 +188,28 @@ fn is_declaration<'a>(statement: &'a Statement<'a>) -> bool {
     _ => false,
   }
 }
+
+/// Collects the symbols referenced or declared in a preserved or kept statement.
+struct SymbolCollector<'s> {
+  symbols: &'s SymbolTable,
+  referenced: std::vec::Vec<SymbolId>,
+  declared: std::vec::Vec<SymbolId>,
+}
+
+impl<'s> SymbolCollector<'s> {
+  fn new(symbols: &'s SymbolTable) -> Self {
+    Self { symbols, referenced: vec![], declared: vec![] }
+  }
+}
+
+impl<'a, 's> Visit<'a> for SymbolCollector<'s> {
+  fn visit_identifier_reference(&mut self, node: &IdentifierReference<'a>) {
+    if let Some(symbol) = self.symbols.get_reference(node.reference_id()).symbol_id() {
+      self.referenced.push(symbol);
+    }
+  }
+
+  fn visit_binding_identifier(&mut self, node: &BindingIdentifier<'a>) {
+    self.declared.push(node.symbol_id());
+  }
+}
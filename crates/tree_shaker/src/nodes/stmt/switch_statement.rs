@@ -20,7 +20,11 @@ impl<'a> Analyzer<'a> {
 
     // 1. discriminant
     let discriminant = self.exec_expression(&node.discriminant);
-    self.push_dependent_cf_scope(discriminant);
+    let dependent_depth = self.push_cf_scope_with_deps(CfScopeKind::Dependent, vec![], Some(false));
+    if node.cases.iter().all(|case| case.test.is_none()) {
+      let dep = self.consumable(discriminant);
+      self.cf_scope_mut().push_dep(dep);
+    }
 
     // 2. tests
     let mut default_case = None;
@@ -31,7 +35,6 @@ impl<'a> Analyzer<'a> {
       if let Some(test) = &case.test {
         let test_val = self.exec_expression(test);
 
-        // TODO: Support mangling
         let (test_result, m) = self.entity_op.strict_eq(self, discriminant, test_val);
         test_results.push(test_result);
 
@@ -39,18 +42,29 @@ impl<'a> Analyzer<'a> {
           data.need_test.insert(index);
         }
 
+        // Like `===`, the comparison only constrains the mangled names instead of preventing mangling
+        let test_result_val = self.factory.boolean_maybe_unknown(test_result);
+        let test_dep = if let Some(m) = m {
+          let m = self.factory.alloc(m);
+          self.consume(&*m);
+          self.factory.mangable(test_result_val, (discriminant, test_val), m)
+        } else {
+          self.factory.computed(test_result_val, (discriminant, test_val))
+        };
+
         match test_result {
           Some(true) => {
+            let test_dep = self.consumable(test_dep);
+            self.scope_context.cf.get_mut_from_depth(dependent_depth).push_dep(test_dep);
             maybe_default_case = Some(false);
             break;
           }
-          Some(false) => {}
+          Some(false) => {
+            let test_dep = self.consumable(test_dep);
+            self.scope_context.cf.get_mut_from_depth(dependent_depth).push_dep(test_dep);
+          }
           None => {
-            self.consume((discriminant, test_val));
-            if let Some(m) = m {
-              m.add_to_mangler(&mut self.mangler);
-            }
-            // data.need_test.insert(index);
+            self.consume(test_dep);
             maybe_default_case = None;
             if !indeterminate {
               indeterminate = true;
@@ -14,6 +14,7 @@ pub struct CallScope<'a> {
   pub returned_values: Vec<Entity<'a>>,
   pub is_async: bool,
   pub is_generator: bool,
+  pub has_await: bool,
   pub try_scopes: Vec<TryScope<'a>>,
   pub need_consume_arguments: bool,
 
@@ -40,6 +41,7 @@ impl<'a> CallScope<'a> {
       returned_values: Vec::new(),
       is_async,
       is_generator,
+      has_await: false,
       try_scopes: vec![TryScope::new(cf_scope_depth)],
       need_consume_arguments: false,
 
@@ -78,8 +80,16 @@ impl<'a> CallScope<'a> {
       analyzer.factory.union(self.returned_values)
     };
 
-    let value = if self.is_async {
+    let value = if self.is_generator && self.is_async {
       analyzer.factory.computed_unknown(analyzer.consumable((value, promise_error)))
+    } else if self.is_async {
+      // Variables read after `await` may have been changed when the function resumes
+      let value = if self.has_await && analyzer.has_exhaustive_read_before(self.cf_scope_depth) {
+        analyzer.factory.computed_unknown(value)
+      } else {
+        value
+      };
+      analyzer.factory.promise(value, promise_error)
     } else {
       value
     };
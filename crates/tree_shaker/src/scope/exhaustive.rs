@@ -3,6 +3,7 @@ use crate::{analyzer::Analyzer, entity::Entity, scope::CfScopeKind};
 use oxc::semantic::{ScopeId, SymbolId};
 use rustc_hash::FxHashSet;
 use std::{
+  cell::Cell,
   hash::{Hash, Hasher},
   mem,
   rc::Rc,
@@ -65,6 +66,28 @@ impl<'a> Analyzer<'a> {
     self.register_exhaustive_callbacks(true, runner, deps);
   }
 
+  pub fn exec_async_fn(
+    &mut self,
+    runner: impl Fn(&mut Analyzer<'a>) -> Entity<'a> + 'a,
+  ) -> Entity<'a> {
+    let promise = Rc::new(Cell::new(None));
+    let runner: Rc<dyn Fn(&mut Analyzer<'a>) + 'a> = Rc::new({
+      let promise = promise.clone();
+      move |analyzer| {
+        let value = runner(analyzer);
+        if promise.get().is_none() {
+          promise.set(Some(value));
+        } else {
+          // Re-executed because of a dependency change, the first result is already returned
+          analyzer.consume(value);
+        }
+      }
+    });
+    let deps = self.exec_exhaustively("async", runner.clone(), true);
+    self.register_exhaustive_callbacks(true, runner, deps);
+    promise.get().unwrap()
+  }
+
   fn exec_exhaustively(
     &mut self,
     kind: &str,
@@ -181,6 +204,21 @@ impl<'a> Analyzer<'a> {
     }
   }
 
+  /// Whether the nearest exhaustive scope below `target_depth` has read outer variables
+  pub fn has_exhaustive_read_before(&self, target_depth: usize) -> bool {
+    self
+      .scope_context
+      .cf
+      .iter_stack()
+      .take(target_depth)
+      .rev()
+      .find_map(|scope| match &scope.kind {
+        CfScopeKind::Exhaustive(data) => Some(!data.deps.is_empty()),
+        _ => None,
+      })
+      .unwrap_or(true)
+  }
+
   pub fn has_exhaustive_scope_since(&self, target_depth: usize) -> bool {
     self.scope_context.cf.iter_stack_range(target_depth..).any(|scope| scope.kind.is_exhaustive())
   }
@@ -20,6 +20,8 @@ pub struct Variable<'a> {
 pub struct VariableScope<'a> {
   pub variables: FxHashMap<SymbolId, &'a RefCell<Variable<'a>>>,
   pub this: Option<Entity<'a>>,
+  /// The value of `super` inside a class body, if known
+  pub super_class: Option<Entity<'a>>,
   pub arguments: Option<(Entity<'a>, Vec<SymbolId>)>,
   pub exhaustive_callbacks: FxHashMap<SymbolId, FxHashSet<ExhaustiveCallback<'a>>>,
 }
@@ -330,6 +332,16 @@ impl<'a> Analyzer<'a> {
     self.mark_unresolved_reference(symbol);
   }
 
+  /// Does nothing if the symbol is not declared in the current scopes.
+  pub fn consume_symbol(&mut self, symbol: SymbolId) {
+    for depth in (0..self.scope_context.variable.stack.len()).rev() {
+      let id = self.scope_context.variable.stack[depth];
+      if self.consume_on_scope(id, symbol) {
+        return;
+      }
+    }
+  }
+
   fn mark_unresolved_reference(&mut self, symbol: SymbolId) {
     if self.semantic.symbols().get_flags(symbol).is_function_scoped_declaration() {
       self.mark_untracked_on_scope(symbol);
@@ -356,4 +368,15 @@ impl<'a> Analyzer<'a> {
     }
     unreachable!()
   }
+
+  pub fn get_super_class(&self) -> Option<Entity<'a>> {
+    for depth in (0..self.scope_context.variable.stack.len()).rev() {
+      let scope = self.scope_context.variable.get_from_depth(depth);
+      if scope.this.is_some() {
+        // Only arrow functions inherit `super`, and they don't have their own `this`
+        return scope.super_class;
+      }
+    }
+    None
+  }
 }
@@ -1,6 +1,6 @@
 use crate::{
-  analyzer::Analyzer, entity::EntityFactory, transformer::Transformer, utils::Diagnostics,
-  TreeShakeConfig,
+  analyzer::Analyzer, entity::EntityFactory, mangling::MangleCache, transformer::Transformer,
+  utils::Diagnostics, TreeShakeConfig,
 };
 use oxc::{
   allocator::Allocator,
@@ -15,11 +15,15 @@ pub struct TreeShakeOptions {
   pub config: TreeShakeConfig,
   pub minify_options: Option<MinifierOptions>,
   pub codegen_options: CodegenOptions,
+  /// The mangled property names of the previous build, which are reused when possible
+  pub mangle_cache: MangleCache,
 }
 
 pub struct TreeShakeReturn {
   pub codegen_return: CodegenReturn,
   pub diagnostics: Diagnostics,
+  /// The input `mangle_cache`, updated with the names mangled in this build
+  pub mangle_cache: MangleCache,
 }
 
 pub struct TreeShakerInner<'a> {
@@ -28,6 +32,8 @@ pub struct TreeShakerInner<'a> {
   pub factory: &'a EntityFactory<'a>,
   pub minify_options: Option<MinifierOptions>,
   pub codegen_options: CodegenOptions,
+  /// Taken by the mangler, so that it is not allocated in the arena
+  pub mangle_cache: RefCell<MangleCache>,
   pub diagnostics: RefCell<BTreeSet<String>>,
 }
 
@@ -36,7 +42,7 @@ pub struct TreeShaker<'a>(pub Rc<TreeShakerInner<'a>>);
 
 impl<'a> TreeShaker<'a> {
   pub fn new(allocator: &'a Allocator, options: TreeShakeOptions) -> Self {
-    let TreeShakeOptions { config, minify_options, codegen_options } = options;
+    let TreeShakeOptions { config, minify_options, codegen_options, mangle_cache } = options;
 
     let config = allocator.alloc(config);
     let factory = allocator.alloc(EntityFactory::new(allocator, config));
@@ -46,15 +52,27 @@ impl<'a> TreeShaker<'a> {
       config,
       minify_options,
       codegen_options,
+      mangle_cache: RefCell::new(mangle_cache),
       factory,
       diagnostics: Default::default(),
     }))
   }
 
   pub fn tree_shake(self, ast: &'a mut Program<'a>) -> TreeShakeReturn {
-    let TreeShakerInner { allocator, config, minify_options, codegen_options, .. } = &*self.0;
+    let TreeShakerInner {
+      allocator, config, minify_options, codegen_options, mangle_cache, ..
+    } = &*self.0;
 
-    let ast = if config.enabled {
+    // The config may be built without being validated, in which case the program is left as is
+    let valid = match config.validate() {
+      Ok(()) => true,
+      Err(err) => {
+        self.0.diagnostics.borrow_mut().insert(err);
+        false
+      }
+    };
+
+    let (ast, mangle_cache) = if config.enabled && valid {
       let semantic_builder = SemanticBuilder::new();
       let semantic = semantic_builder.build(ast).semantic;
 
@@ -64,9 +82,10 @@ impl<'a> TreeShaker<'a> {
 
       // Step 2: Remove dead code (transform)
       let transformer = Transformer::new(analyzer);
-      allocator.alloc(transformer.transform_program(ast))
+      let ast = allocator.alloc(transformer.transform_program(ast));
+      (ast, transformer.mangler.into_inner().into_cache())
     } else {
-      ast
+      (ast, mangle_cache.take())
     };
 
     // Step 3: Minify
@@ -81,6 +100,6 @@ impl<'a> TreeShaker<'a> {
       .with_mangler(minifier_return.and_then(|r| r.mangler));
     let codegen_return = codegen.build(ast);
 
-    TreeShakeReturn { codegen_return, diagnostics: self.0.diagnostics.take() }
+    TreeShakeReturn { codegen_return, diagnostics: self.0.diagnostics.take(), mangle_cache }
   }
 }
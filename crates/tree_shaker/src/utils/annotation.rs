@@ -1,30 +1,43 @@
 use crate::analyzer::Analyzer;
-use oxc::{ast::CommentKind, span::Span};
+use oxc::span::Span;
+
+/// Bindings which are implicitly referenced by JSX elements and fragments.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct JsxPragma<'a> {
+  pub factory: Option<&'a str>,
+  pub fragment: Option<&'a str>,
+}
 
 impl<'a> Analyzer<'a> {
   fn has_annotation(&self, span: Span, test: fn(&str) -> bool) -> bool {
-    let Some(comment) = self.semantic.comments_range(..span.start).next_back() else {
-      return false;
-    };
+    self.has_annotation_with_prefix(span, |prefix| prefix.is_empty(), test)
+  }
+
+  /// `allow_prefix` receives the trimmed source text between the comment and the `span`.
+  fn has_annotation_with_prefix(
+    &self,
+    span: Span,
+    allow_prefix: fn(&str) -> bool,
+    test: fn(&str) -> bool,
+  ) -> bool {
+    self.get_annotation_with_prefix(span, allow_prefix).is_some_and(test)
+  }
+
+  /// Returns the raw text of the comment right before the `span`.
+  fn get_annotation_with_prefix(
+    &self,
+    span: Span,
+    allow_prefix: fn(&str) -> bool,
+  ) -> Option<&'a str> {
+    let comment = self.semantic.comments_range(..span.start).next_back()?;
     let raw = comment.span.source_text(self.semantic.source_text());
 
-    // If there are non-whitespace characters between the `comment` and the `span`,
+    // If there are other characters between the `comment` and the `span`,
     // we treat the `comment` not belongs to the `span`.
+    // The span of `comment` includes the delimiters.
     let range_text =
       Span::new(comment.span.end, span.start).source_text(self.semantic.source_text());
-    let only_whitespace = match comment.kind {
-      CommentKind::Line => range_text.trim().is_empty(),
-      CommentKind::Block => {
-        range_text
-          .strip_prefix("*/") // for multi-line comment
-          .is_some_and(|s| s.trim().is_empty())
-      }
-    };
-    if !only_whitespace {
-      return false;
-    }
-
-    test(raw)
+    allow_prefix(range_text.trim()).then_some(raw)
   }
 
   pub fn has_pure_notation(&self, span: Span) -> usize {
@@ -40,4 +53,94 @@ impl<'a> Analyzer<'a> {
       raw.contains("@__FINITE_RECURSION__") || raw.contains("#__FINITE_RECURSION__")
     })
   }
+
+  /// `@__RECURSION_DEPTH__(n)`, which overrides `config.max_recursion_depth` for the function.
+  pub fn get_recursion_depth_notation(&self, span: Span) -> Option<usize> {
+    let raw = self.get_annotation_with_prefix(span, |prefix| prefix.is_empty())?;
+    let (_, rest) = raw.split_once("__RECURSION_DEPTH__(")?;
+    let (depth, _) = rest.split_once(')')?;
+    depth.trim().parse().ok()
+  }
+
+  /// `@__NOINLINE__`: calls to the function are treated as unknown calls.
+  pub fn has_no_inline_notation(&self, span: Span) -> bool {
+    self.has_annotation(span, |raw| raw.contains("@__NOINLINE__") || raw.contains("#__NOINLINE__"))
+  }
+
+  /// `@__ALWAYS_ANALYZE__`: calls to the function are still analyzed after it has been consumed.
+  pub fn has_always_analyze_notation(&self, span: Span) -> bool {
+    self.has_annotation(span, |raw| {
+      raw.contains("@__ALWAYS_ANALYZE__") || raw.contains("#__ALWAYS_ANALYZE__")
+    })
+  }
+
+  /// Rollup's `@__NO_SIDE_EFFECTS__`, which may also be placed before `export`, `export default`
+  /// and `const f =` of the annotated function.
+  pub fn has_no_side_effects_notation(&self, span: Span) -> bool {
+    self.has_annotation_with_prefix(
+      span,
+      |prefix| {
+        let mut words = prefix.split_whitespace();
+        let mut word = words.next();
+        if word == Some("export") {
+          word = words.next();
+          if word == Some("default") {
+            word = words.next();
+          }
+        }
+        match word {
+          None => true,
+          Some("const" | "let" | "var") => {
+            words.next().is_some_and(|id| !id.contains('='))
+              && words.next() == Some("=")
+              && words.next().is_none()
+          }
+          _ => false,
+        }
+      },
+      |raw| raw.contains("@__NO_SIDE_EFFECTS__") || raw.contains("#__NO_SIDE_EFFECTS__"),
+    )
+  }
+
+  /// `// tree-shaker-keep` before a statement
+  pub fn has_keep_directive(&self, span: Span) -> bool {
+    self.has_annotation(span, |raw| raw.contains("tree-shaker-keep"))
+  }
+
+  /// `// tree-shaker-ignore-start` before the first statement of an ignored region
+  pub fn has_ignore_start_directive(&self, span: Span) -> bool {
+    self.has_annotation(span, |raw| raw.contains("tree-shaker-ignore-start"))
+  }
+
+  /// `// tree-shaker-ignore-end` before the first statement after an ignored region
+  pub fn has_ignore_end_directive(&self, span: Span) -> bool {
+    self.has_annotation(span, |raw| raw.contains("tree-shaker-ignore-end"))
+  }
+
+  /// Resolves `@jsx`, `@jsxFrag`, `@jsxRuntime` and `@jsxImportSource` pragmas, falling back to
+  /// the classic runtime of the configured preset.
+  pub fn get_jsx_pragma(&self) -> JsxPragma<'a> {
+    let source_text = self.semantic.source_text();
+    let mut factory = None;
+    let mut fragment = None;
+    let mut automatic = false;
+    for comment in self.semantic.comments() {
+      let raw = comment.span.source_text(source_text);
+      let mut words = raw.split(|c: char| c.is_whitespace() || c == '*').filter(|w| !w.is_empty());
+      while let Some(word) = words.next() {
+        match word {
+          "@jsx" => factory = words.next(),
+          "@jsxFrag" => fragment = words.next(),
+          "@jsxRuntime" => automatic = words.next() == Some("automatic"),
+          "@jsxImportSource" => automatic = words.next().is_some(),
+          _ => {}
+        }
+      }
+    }
+
+    let classic = if automatic { None } else { self.config.jsx.classic_runtime() };
+    let factory = factory.or(classic.map(|(factory, _)| factory));
+    let fragment = factory.and(fragment.or(classic.and_then(|(_, fragment)| fragment)));
+    JsxPragma { factory, fragment }
+  }
 }
@@ -1,7 +1,7 @@
 use crate::{analyzer::Analyzer, consumable::ConsumableTrait};
 use oxc::{
   allocator::Vec,
-  ast::ast::*,
+  ast::{ast::*, match_member_expression},
   span::{GetSpan, SPAN},
 };
 use std::fmt::{self, Debug};
@@ -311,3 +311,22 @@ impl DeclarationKind {
       )
   }
 }
+
+/// Returns the path of a global or imported value like `a.b.c`, and the root identifier
+pub fn get_static_path<'a>(
+  node: &'a Expression<'a>,
+) -> Option<(String, &'a IdentifierReference<'a>)> {
+  match node {
+    Expression::Identifier(node) => Some((node.name.to_string(), node)),
+    match_member_expression!(Expression) => {
+      let node = node.to_member_expression();
+      let property = node.static_property_name()?;
+      let (mut path, root) = get_static_path(node.object())?;
+      path.push('.');
+      path.push_str(property);
+      Some((path, root))
+    }
+    Expression::ParenthesizedExpression(node) => get_static_path(&node.expression),
+    _ => None,
+  }
+}
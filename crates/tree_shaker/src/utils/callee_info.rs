@@ -36,6 +36,7 @@ impl PartialEq for CalleeNode<'_> {
         a.span() == b.span()
       }
       (CalleeNode::ClassStatics(a), CalleeNode::ClassStatics(b)) => a.span() == b.span(),
+      (CalleeNode::ClassConstructor(a), CalleeNode::ClassConstructor(b)) => a.span() == b.span(),
       _ => false,
     }
   }
@@ -1,5 +1,5 @@
 use crate::dep::DepId;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::collections::BTreeSet;
 
 pub struct DataPlaceholder<'a> {
@@ -13,4 +13,8 @@ pub type Diagnostics = BTreeSet<String>;
 #[derive(Debug, Default)]
 pub struct StatementVecData {
   pub last_stmt: Option<usize>,
+  /// Statements marked by `tree-shaker-keep`, which are always referred
+  pub kept_stmts: FxHashSet<usize>,
+  /// Statements inside `tree-shaker-ignore-start/end` regions, which are emitted as is
+  pub preserved_stmts: FxHashSet<usize>,
 }
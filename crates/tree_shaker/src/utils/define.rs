@@ -0,0 +1,82 @@
+use super::ast::get_static_path;
+use crate::{
+  analyzer::Analyzer,
+  entity::{Entity, LiteralEntity},
+  transformer::Transformer,
+  TreeShakeConfig,
+};
+use oxc::{ast::ast::Expression, semantic::SymbolTable};
+
+/// Parses a value of `config.define` or `config.import_meta`, which is a JS literal.
+pub fn parse_define_value(value: &str) -> Option<LiteralEntity<'_>> {
+  match value {
+    "true" => Some(LiteralEntity::Boolean(true)),
+    "false" => Some(LiteralEntity::Boolean(false)),
+    "null" => Some(LiteralEntity::Null),
+    "undefined" | "void 0" => Some(LiteralEntity::Undefined),
+    "NaN" => Some(LiteralEntity::NaN),
+    "Infinity" => Some(LiteralEntity::Infinity(true)),
+    "-Infinity" => Some(LiteralEntity::Infinity(false)),
+    _ => {
+      let unquoted = value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .or_else(|| value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')));
+      if let Some(unquoted) = unquoted {
+        // Escape sequences are not supported
+        (!unquoted.contains('\\')).then_some(LiteralEntity::String(unquoted, None))
+      } else if let Some((radix, digits)) = parse_radix_prefix(value) {
+        let number = u64::from_str_radix(digits, radix).ok()? as f64;
+        Some(LiteralEntity::Number(number.into(), None))
+      } else {
+        let number = value.parse::<f64>().ok().filter(|n| n.is_finite())?;
+        Some(LiteralEntity::Number(number.into(), None))
+      }
+    }
+  }
+}
+
+fn parse_radix_prefix(value: &str) -> Option<(u32, &str)> {
+  let prefix = value.get(..2)?;
+  let digits = &value[2..];
+  let radix = match prefix {
+    "0x" | "0X" => 16,
+    "0o" | "0O" => 8,
+    "0b" | "0B" => 2,
+    _ => return None,
+  };
+  // `from_str_radix` accepts a sign, which is not valid here
+  digits.bytes().all(|b| b.is_ascii_alphanumeric()).then_some((radix, digits))
+}
+
+fn get_define<'a>(
+  config: &'a TreeShakeConfig,
+  symbols: &SymbolTable,
+  node: &'a Expression<'a>,
+) -> Option<LiteralEntity<'a>> {
+  if config.define.is_empty() {
+    return None;
+  }
+  let (path, root) = get_static_path(node)?;
+  if symbols.get_reference(root.reference_id()).symbol_id().is_some() {
+    return None;
+  }
+  // The values are validated before tree shaking
+  parse_define_value(config.define.get(&path)?)
+}
+
+impl<'a> Analyzer<'a> {
+  pub fn is_defined(&self, node: &'a Expression<'a>) -> bool {
+    get_define(self.config, self.semantic.symbols(), node).is_some()
+  }
+
+  pub fn exec_define(&mut self, node: &'a Expression<'a>) -> Entity<'a> {
+    self.factory.alloc(get_define(self.config, self.semantic.symbols(), node).unwrap())
+  }
+}
+
+impl<'a> Transformer<'a> {
+  pub fn get_define(&self, node: &'a Expression<'a>) -> Option<LiteralEntity<'a>> {
+    get_define(self.config, self.semantic.symbols(), node)
+  }
+}
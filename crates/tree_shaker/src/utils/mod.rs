@@ -2,6 +2,7 @@ mod annotation;
 pub mod ast;
 mod callee_info;
 mod data;
+mod define;
 pub mod dep_id;
 pub mod effect_builder;
 mod escape_template_element_value;
@@ -11,7 +12,9 @@ mod get_two_mut;
 mod private_identifier_name;
 mod symbol_id;
 
+pub use annotation::JsxPragma;
 pub use callee_info::*;
 pub use data::*;
+pub use define::parse_define_value;
 pub use f64_with_eq::*;
 pub use get_two_mut::*;
@@ -0,0 +1,92 @@
+use rustc_hash::FxHashMap;
+use tree_shaker::{tree_shake, TreeShakeConfig, TreeShakeJsxPreset, TreeShakeOptions};
+
+#[test]
+fn json_config() {
+  let config = TreeShakeConfig::default()
+    .with_json(
+      r#"{
+        "preset": "smallest",
+        "jsx": "preact",
+        "mangling": false,
+        "define": { "process.env.NODE_ENV": "\"production\"" },
+        "import_meta": { "env.SSR": "false", "hot": null }
+      }"#,
+    )
+    .unwrap();
+  assert!(!config.mangling);
+  assert!(!config.iterate_side_effects);
+  assert_eq!(config.jsx, TreeShakeJsxPreset::Preact);
+  assert_eq!(config.define["process.env.NODE_ENV"], "\"production\"");
+  assert_eq!(config.import_meta["env.SSR"].as_deref(), Some("false"));
+  assert_eq!(config.import_meta["hot"], None);
+}
+
+#[test]
+fn toml_config() {
+  let config = TreeShakeConfig::recommended()
+    .with_toml(
+      r#"
+        max_recursion_depth = 4
+        manual_pure_functions = ["console.log"]
+
+        [define]
+        DEBUG = "false"
+      "#,
+    )
+    .unwrap();
+  assert!(config.mangling);
+  assert_eq!(config.max_recursion_depth, 4);
+  assert_eq!(config.manual_pure_functions, ["console.log"]);
+  assert_eq!(config.define["DEBUG"], "false");
+}
+
+#[test]
+fn disable_always_inline_literal() {
+  let base = TreeShakeConfig::recommended().with_always_inline_literal(true);
+  let config = base.with_json(r#"{ "always_inline_literal": false }"#).unwrap();
+  assert_eq!(config.max_simple_string_length, TreeShakeConfig::default().max_simple_string_length);
+  assert_eq!(config.max_simple_number_value, TreeShakeConfig::default().max_simple_number_value);
+}
+
+#[test]
+fn invalid_config() {
+  assert!(TreeShakeConfig::default().with_json(r#"{ "preset": "unknown" }"#).is_err());
+  assert!(TreeShakeConfig::default().with_json(r#"{ "unknown": true }"#).is_err());
+  assert!(TreeShakeConfig::default().with_json(r#"{ "define": { "X": "{}" } }"#).is_err());
+  assert!(TreeShakeConfig::default().with_json(r#"{ "define": { "X": "0x10" } }"#).is_ok());
+  assert!(TreeShakeConfig::default()
+    .with_json(r#"{ "import_meta": { "env": "null", "env.MODE": "\"x\"" } }"#)
+    .is_err());
+  assert!(TreeShakeConfig::default().with_json(r#"{ "mangle_include": "(" }"#).is_err());
+}
+
+#[test]
+fn invalid_programmatic_config() {
+  let mut define = FxHashMap::default();
+  define.insert("X".to_string(), "{}".to_string());
+  let result = tree_shake(
+    "export const x = X;".to_string(),
+    TreeShakeOptions {
+      config: TreeShakeConfig::default().with_define(define),
+      minify_options: None,
+      codegen_options: Default::default(),
+      mangle_cache: Default::default(),
+    },
+  );
+  assert!(result.diagnostics.iter().any(|d| d.contains("Invalid define value for X")));
+
+  let mut import_meta = FxHashMap::default();
+  import_meta.insert("env".to_string(), None);
+  import_meta.insert("env.MODE".to_string(), Some("\"dev\"".to_string()));
+  let result = tree_shake(
+    "export const mode = import.meta.env.MODE;".to_string(),
+    TreeShakeOptions {
+      config: TreeShakeConfig::default().with_import_meta(import_meta),
+      minify_options: None,
+      codegen_options: Default::default(),
+      mangle_cache: Default::default(),
+    },
+  );
+  assert!(result.diagnostics.iter().any(|d| d.contains("Conflicting import.meta config")));
+}
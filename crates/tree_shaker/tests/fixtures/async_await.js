@@ -34,3 +34,45 @@ export async function pure_but_complex(unknown) {
   const f = async () => unknown;
   return await f();
 }
+
+async function getConfig() {
+  return { debug: false, name: "app" };
+}
+
+export async function main() {
+  const config = await getConfig();
+  if (config.debug) {
+    console.log("debug");
+  }
+  return config.name;
+}
+
+async function mayThrow(a) {
+  if (a) throw new Error("a");
+  return 1;
+}
+
+export async function main2() {
+  try {
+    await mayThrow(unknown);
+  } catch {
+    return "caught";
+  }
+  const value = await mayThrow(false);
+  return value === 1 ? "ok" : "fail";
+}
+
+let state = 1;
+async function readAfterAwait() {
+  await something;
+  return state;
+}
+
+export async function main3() {
+  const p = readAfterAwait();
+  state = 2;
+  return (await p) === 1;
+}
+
+const asyncArrow = async () => ({ value: 1 });
+export const r6 = (await asyncArrow()).value;
@@ -32,3 +32,32 @@ class C {
     effect(8);
   }
 }
+
+class Accessors {
+  a = 1;
+  constructor(x) {
+    this.x = x;
+  }
+  get double() {
+    return this.x * 2;
+  }
+  set double(v) {
+    this.x = v / 2;
+  }
+  get unused() {
+    return effect();
+  }
+  method() {
+    return this.a;
+  }
+  static get s() {
+    return 1;
+  }
+  static get unused_static() {
+    return effect();
+  }
+}
+const acc = new Accessors(2);
+export const acc1 = acc.double;
+acc.double = 10;
+export const acc2 = [acc.x, acc.method(), Accessors.s];
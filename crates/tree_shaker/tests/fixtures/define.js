@@ -0,0 +1,24 @@
+// @define process.env.NODE_ENV="production" __DEV__=false FEATURE_X=true MAX_ITEMS=3 LEGACY=null FLAGS=0x10 RATIO=NaN LIMIT=Infinity EXP=1e2 SCALE=1.50
+if (process.env.NODE_ENV !== "production") {
+  console.warn("development only");
+}
+
+export function log(message) {
+  if (__DEV__) {
+    console.log(message);
+  }
+  return FEATURE_X ? "new" : "old";
+}
+
+export const items = Array.from({ length: MAX_ITEMS }, (_, i) => i);
+export const legacy = LEGACY ?? "modern";
+export const env = process.env.NODE_ENV;
+export const other = process.env.OTHER;
+
+function shadowed(__DEV__) {
+  return __DEV__;
+}
+export const s = shadowed(unknown);
+
+export const numbers = [FLAGS, RATIO, LIMIT];
+export const strings = ["" + EXP, `${SCALE}px`];
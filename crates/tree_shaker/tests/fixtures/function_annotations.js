@@ -0,0 +1,25 @@
+export function recursion_depth() {
+  /* @__RECURSION_DEPTH__(5) */
+  function count(n) {
+    return n >= 4 ? n : count(n + 1);
+  }
+  return count(0);
+}
+
+export function no_inline() {
+  /* @__NOINLINE__ */
+  function get() {
+    return 1;
+  }
+  return get();
+}
+
+export function always_analyze(cb) {
+  /* @__ALWAYS_ANALYZE__ */
+  function get() {
+    return 1;
+  }
+  const normal = () => 2;
+  cb(get, normal);
+  return [get(), normal()];
+}
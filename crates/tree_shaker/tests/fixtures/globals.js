@@ -0,0 +1,108 @@
+export function uri() {
+  t1 = encodeURIComponent("a&b")
+  t2 = encodeURI("/a b?q")
+  t3 = decodeURIComponent("a%20b%26c%3D%C3%A4")
+  t4 = decodeURI("%3Fa%20b")
+  t5 = escape("ä€")
+  const unused = encodeURIComponent(unknown)
+  try {
+    decodeURIComponent("%E0%A4%A")
+  } catch {
+    t6 = 1
+  }
+  try {
+    decodeURIComponent(unknown)
+  } catch {
+    t7 = 1
+  }
+  try {
+    decodeURIComponent("%+1")
+  } catch {
+    t8 = 1
+  }
+}
+
+export function base64() {
+  t1 = btoa("hello")
+  t2 = atob("aGVsbG8=")
+  t3 = atob(btoa("ab"))
+  t4 = atob("aGVsbG8gd29ybGQ")
+  const unused = btoa(unknown)
+}
+
+export function numeric() {
+  t1 = isFinite("12")
+  t2 = isFinite(1 / 0)
+  t3 = Number.isFinite("12")
+  t4 = Number.parseFloat("  3.14abc")
+  t5 = parseFloat("-.5e2x")
+  t6 = parseFloat("abc")
+  t7 = parseFloat("-Infinityx")
+  t8 = "" + parseFloat("1e2x")
+  t9 = parseFloat("1.50") + "px"
+  t10 = typeof Number
+  t11 = Number("12")
+  const unused = parseFloat(unknown)
+}
+
+export function reflect() {
+  const obj = { a: 1, b: 2, c: 3 }
+  t1 = Reflect.get(obj, "a")
+  Reflect.set(obj, "b", 4)
+  t2 = obj.b
+  t3 = Reflect.apply(function (x, y) { return x + y + this.z }, { z: 1 }, [2, 3])
+  t4 = Reflect.ownKeys({ x: 1, y: 2 })
+  const hidden = { x: 1 }
+  Object.defineProperty(hidden, "y", { value: 2 })
+  t7 = Reflect.ownKeys(hidden).length
+  class A { constructor(v) { this.v = v } }
+  t5 = Reflect.construct(A, [5]).v
+  Reflect.deleteProperty(obj, "c")
+  t6 = obj.c
+}
+
+export function proxy() {
+  const target = { a: 1, b: 2 }
+  const p1 = new Proxy(target, {})
+  t1 = p1.a
+  const p2 = new Proxy(target, {
+    get(target, key) {
+      return key === "a" ? 10 : target[key]
+    },
+    unused() {}
+  })
+  t2 = p2.a
+  t3 = p2.b
+  const p3 = new Proxy(function (x) { return x * 2 }, {
+    apply(target, thisArg, args) {
+      return target(args[0]) + 1
+    }
+  })
+  t4 = p3(3)
+  const unused = new Proxy({}, { get() { effect() } })
+  const p4 = new Proxy(target, unknown)
+  t5 = p4.a
+}
+
+export function typed_arrays() {
+  const table = new Uint8Array(256)
+  const unusedBuffer = new ArrayBuffer(1024)
+  t1 = new Int32Array(4).byteLength
+  t2 = new ArrayBuffer(8).byteLength
+  const view = new DataView(new ArrayBuffer(16))
+  t3 = view.byteLength
+  const kept = new Float64Array(2)
+  kept[0] = 1
+  t4 = kept
+  const fromArray = new Uint16Array([1, 2, 3])
+  t5 = fromArray.length
+  try {
+    new ArrayBuffer(-1)
+  } catch {
+    t6 = 1
+  }
+  const shared = new ArrayBuffer(4)
+  new Uint8Array(shared)[0] = 1
+  new DataView(shared).setUint8(1, 2)
+  t7 = shared
+}
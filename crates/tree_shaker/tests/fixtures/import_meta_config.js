@@ -0,0 +1,17 @@
+// @import-meta env.MODE="production" env.SSR=false env.DEV=false hot
+
+if (import.meta.hot) {
+  import.meta.hot.accept(() => console.log('hot updated'));
+}
+
+export function render() {
+  if (import.meta.env.SSR) {
+    return 'server';
+  }
+  return import.meta.env.DEV ? 'dev' : 'client';
+}
+
+export const mode = import.meta.env.MODE;
+export const isProd = import.meta.env.MODE === 'production';
+export const base = import.meta.env.BASE_URL;
+export const url = import.meta.url;
@@ -0,0 +1,6 @@
+// @import-meta env.MODE="production" env.SSR=false
+
+import.meta.env.MODE = 'dev';
+
+export const mode = import.meta.env.MODE;
+export const ssr = import.meta.env.SSR;
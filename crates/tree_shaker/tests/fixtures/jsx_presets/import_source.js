@@ -0,0 +1,7 @@
+// @preact-jsx
+/** @jsxImportSource preact */
+import { h } from "preact";
+
+export function main() {
+  return <div>{1}</div>;
+}
@@ -0,0 +1,19 @@
+// @react-jsx
+/** @jsx h */
+/** @jsxFrag Frag */
+import { h, Frag } from "my-lib";
+import { unused } from "my-lib";
+
+function Comp({ x }) {
+  return <span>{x}</span>;
+}
+
+export function main() {
+  return <Frag>
+    <Comp x={1} />
+  </Frag>;
+}
+
+export function fragment() {
+  return <>{main()}</>;
+}
@@ -0,0 +1,17 @@
+// @preact-jsx
+import { h, Fragment, createElement } from "preact";
+
+function Comp({ x, y }) {
+  return <div>{x}</div>;
+}
+
+function Unused() {
+  return <>unused</>;
+}
+
+export function main() {
+  return <>
+    <Comp x={1} y={effect()} />
+    {h(Comp, { x: 2, y: 3 }, "child")}
+  </>;
+}
@@ -0,0 +1,12 @@
+// @solid-jsx
+import { createComponent } from "solid-js/web";
+
+function Comp(props) {
+  effect(props.a);
+  return 1;
+}
+
+export function main() {
+  const unused = createComponent(Comp, { a: 1, b: 2 });
+  return createComponent(Comp, { a: 3 });
+}
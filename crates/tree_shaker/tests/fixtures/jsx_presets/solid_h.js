@@ -0,0 +1,16 @@
+// @solid-jsx
+import h from "solid-js/h";
+
+function Comp(props) {
+  effect(props.a);
+  return <div>{props.a}</div>;
+}
+
+function Unused() {
+  return <span />;
+}
+
+export function main() {
+  const unused = h(Comp, { a: 1, b: 2 });
+  return <Comp a={3} b={4} />;
+}
@@ -0,0 +1,14 @@
+// @vue-jsx
+import { h, createVNode } from "vue";
+
+function Comp({ msg, unused }) {
+  return h("div", null, msg);
+}
+
+export function main() {
+  return [
+    <Comp msg="a" unused={1} />,
+    h(Comp, { msg: "b", unused: 2 }),
+    createVNode(Comp, { msg: "c" }, null, 8, ["msg"]),
+  ];
+}
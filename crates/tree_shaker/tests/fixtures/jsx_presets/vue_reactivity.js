@@ -0,0 +1,33 @@
+import { ref, computed, defineComponent } from "vue";
+
+export function onlyRefEscapes() {
+  const count = ref(1);
+  return count;
+}
+
+export function onlySetterEscapes() {
+  const count = ref(1);
+  const double = computed({
+    get: () => count.value * 2,
+    set: (value) => { count.value = value / 2; },
+  });
+  return (value) => {
+    double.value = value;
+  };
+}
+
+export const Comp = defineComponent({
+  name: "Comp",
+  setup: () => () => null,
+});
+
+export function onlySetterUsed() {
+  const writable = computed({ get: () => 1, set: (value) => effect(value) });
+  writable.value = 2;
+}
+
+export function onlyRefUsed(x) {
+  const count = ref(x);
+  count.value = 2;
+  effect(count.value);
+}
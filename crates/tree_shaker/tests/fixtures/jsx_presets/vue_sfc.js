@@ -0,0 +1,34 @@
+import { defineComponent, ref, computed, openBlock, createElementBlock, createElementVNode, createVNode, withCtx, toDisplayString } from "vue";
+
+const Unused = defineComponent({
+  setup() {
+    return () => createElementVNode("div", null, "unused");
+  },
+});
+
+const Child = defineComponent({
+  props: { msg: String },
+  setup(props) {
+    const count = ref(1);
+    const double = computed(() => count.value * 2);
+    const unusedRef = ref(effect());
+    const unusedComputed = computed(() => 1);
+    return () => (openBlock(), createElementBlock("div", null, [
+      createElementVNode("span", null, toDisplayString(props.msg), 1),
+      createElementVNode("span", null, double.value, 1),
+    ]));
+  },
+});
+
+export default defineComponent({
+  setup() {
+    const writable = computed({
+      get: () => 1,
+      set: (value) => effect(value),
+    });
+    writable.value = 2;
+    return () => createVNode(Child, { msg: "hello" }, {
+      default: withCtx(() => [createElementVNode("b", null, "slot")]),
+    });
+  },
+});
@@ -0,0 +1,42 @@
+function setup() {}
+function teardown() {}
+
+// tree-shaker-keep
+setup();
+
+teardown();
+
+// tree-shaker-keep
+function exposed() {
+  const dead = 1;
+  if (false) {
+    console.log("unreachable");
+  }
+  return 2;
+}
+
+export function main(a) {
+  const unused = a + 1;
+  // tree-shaker-keep
+  const kept = { value: a };
+
+  // tree-shaker-ignore-start
+  let x = 1;
+  if (false) {
+    console.log(x);
+  }
+  const obj = { prop: a };
+  // tree-shaker-ignore-end
+  const removed = 2;
+
+  return obj.prop;
+}
+
+export function nested(a) {
+  if (a) {
+    // tree-shaker-ignore-start
+    void 0;
+    const helper = () => 1;
+  }
+  return 1;
+}
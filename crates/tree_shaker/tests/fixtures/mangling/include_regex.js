@@ -0,0 +1,10 @@
+// @mangle-include ^_
+
+export function main(aaa, bbb) {
+  const obj = {
+    _private: aaa,
+    public: bbb,
+  };
+  console.log(obj._private);
+  console.log(obj.public);
+}
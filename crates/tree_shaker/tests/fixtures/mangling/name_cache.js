@@ -0,0 +1,14 @@
+// @mangle-cache bar=x foo=a baz=x
+
+export function main(aaa, bbb, ccc, ddd) {
+  const obj = {
+    foo: aaa,
+    bar: bbb,
+    baz: ccc,
+    qux: ddd,
+  };
+  console.log(obj.foo);
+  console.log(obj.bar);
+  console.log(obj.baz);
+  console.log(obj.qux);
+}
@@ -0,0 +1,31 @@
+// @mangle-reserved a userId
+// @mangle-exclude ^api_
+
+export function reserved(aaa, bbb, ccc) {
+  const obj = {
+    a: aaa,
+    userId: bbb,
+    other: ccc,
+  };
+  console.log(obj.a);
+  console.log(obj.userId);
+  console.log(obj.other);
+}
+
+export function excluded(aaa, bbb) {
+  const obj = {
+    api_token: aaa,
+    internal: bbb,
+  };
+  console.log(obj.api_token);
+  console.log(obj.internal);
+}
+
+export function computed(unknown, aaa, bbb) {
+  const key = unknown ? 'userId' : 'other';
+  const obj = {
+    userId: aaa,
+    other: bbb,
+  };
+  console.log(obj[key]);
+}
@@ -0,0 +1,56 @@
+export function reducer(unknown) {
+  function reduce(state, action) {
+    switch (action.type) {
+      case "increment":
+        return state + 1;
+      case "decrement":
+        return state - 1;
+      default:
+        return state;
+    }
+  }
+  const action = unknown ? { type: "increment" } : { type: "decrement" };
+  console.log(reduce(0, action));
+}
+
+export function known_discriminant() {
+  function f(x) {
+    switch (x) {
+      case "foo":
+        console.log("x is foo");
+        break;
+      case "bar":
+        console.log("x is bar");
+        break;
+    }
+  }
+  f("foo")
+  f("bar")
+}
+
+export function unknown_discriminant(unknown) {
+  const key = unknown ? "foo" : "bar";
+  switch (key) {
+    case "foo":
+      console.log("key is foo");
+      break;
+    case "bar":
+      console.log("key is bar");
+      break;
+    case "baz":
+      console.log("unreachable");
+  }
+}
+
+export function escaped_discriminant(unknown) {
+  const key = unknown ? "foo" : "bar";
+  unknown(key);
+  switch (key) {
+    case "foo":
+      console.log("key is foo");
+      break;
+    case "bar":
+      console.log("key is bar");
+      break;
+  }
+}
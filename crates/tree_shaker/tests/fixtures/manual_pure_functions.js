@@ -0,0 +1,22 @@
+// @manual-pure-functions console.log myLib.track styled
+import * as myLib from "my-lib";
+import styled from "styled";
+
+console.log("dropped", effect());
+export const a = console.log("kept");
+console.warn("not pure");
+
+myLib.track("dropped");
+myLib.track.deep("dropped");
+myLib.trackAll("not pure");
+export const b = myLib.track(1);
+
+const Button = styled.button("color: red");
+const Unused = styled("div");
+export { Button };
+
+function local() {
+  const console = { log: effect };
+  console.log("local binding is not pure");
+}
+local();
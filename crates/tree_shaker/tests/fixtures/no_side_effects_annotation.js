@@ -0,0 +1,37 @@
+/* @__NO_SIDE_EFFECTS__ */
+function declaration(a) {
+  effect();
+  return a;
+}
+declaration(1);
+declaration(other1());
+export const a = declaration(2);
+
+const expression = /* @__NO_SIDE_EFFECTS__ */ function () {
+  return unknown();
+};
+expression();
+
+/* #__NO_SIDE_EFFECTS__ */ const arrow = (x) => effect(x);
+arrow(1);
+export const b = arrow(2);
+
+/* @__NO_SIDE_EFFECTS__ */ export function exported() {
+  effect();
+}
+exported();
+
+export /* @__NO_SIDE_EFFECTS__ */ function exported2() {
+  effect();
+}
+exported2();
+
+export default /* @__NO_SIDE_EFFECTS__ */ function () {
+  effect();
+}
+
+/* @__NO_SIDE_EFFECTS__ */ effect();
+function notAnnotated() {
+  effect();
+}
+notAnnotated();
@@ -49,3 +49,15 @@ export function modify_self_in_getter() {
   };
   return changed;
 }
+
+export function define_property() {
+  const obj = { a: 1 };
+  Object.defineProperty(obj, "x", { get() { return this.a + 1 } });
+  Object.defineProperty(obj, "unused", { get() { return effect() } });
+  Object.defineProperties(obj, {
+    y: { value: 2 },
+    z: { value: 3, writable: true, enumerable: true },
+  });
+  obj.z = 5;
+  return [obj.x, obj.y, obj.z, Object.keys(obj)];
+}
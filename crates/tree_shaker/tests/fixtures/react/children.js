@@ -0,0 +1,35 @@
+// @react-jsx
+
+import React from 'react';
+
+function Button(props) {
+  return <button className={props.className}>{props.children}</button>;
+}
+
+function Toolbar({ children }) {
+  const unusedCount = React.Children.count(children);
+  const unusedArray = React.Children.toArray(children);
+  const unusedMapped = React.Children.map(children, (child) => effect(child));
+  return (
+    <React.Fragment>
+      {React.Children.map(children, (child, index) =>
+        React.isValidElement(child) ? React.cloneElement(child, { className: "item-" + index }) : child
+      )}
+    </React.Fragment>
+  );
+}
+
+export function main() {
+  const element = <Button className="a">Hi</Button>;
+  const cloned = React.cloneElement(element, { className: "b" });
+  t1 = React.isValidElement("text");
+  t2 = cloned.props.className;
+  t3 = cloned.type === Button;
+  t4 = typeof React.Fragment;
+  return (
+    <Toolbar>
+      <Button>One</Button>
+      {cloned}
+    </Toolbar>
+  );
+}
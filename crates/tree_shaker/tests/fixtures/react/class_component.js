@@ -0,0 +1,59 @@
+// @react-jsx
+import React, { Component, PureComponent } from "react";
+
+class Counter extends Component {
+  static defaultProps = { step: 1 };
+  state = { count: this.props.initial };
+  constructor(props) {
+    super(props);
+    this.unusedField = 1;
+  }
+  handleClick = () => {
+    this.setState({ count: this.state.count + this.props.step });
+  }
+  unusedMethod() {
+    return effect();
+  }
+  componentDidMount() {
+    log("mounted");
+  }
+  render() {
+    return <button onClick={this.handleClick}>{this.state.count}</button>;
+  }
+}
+
+class Label extends React.PureComponent {
+  render() {
+    return <span>{this.props.text}</span>;
+  }
+}
+
+class WithInner extends Component {
+  constructor(props) {
+    super(props);
+    const Inner = class {
+      kind() {
+        return typeof super.call;
+      }
+    };
+    this.kind = new Inner().kind();
+  }
+  render() {
+    return <p>{this.kind}</p>;
+  }
+}
+
+class Unused extends Component {
+  render() {
+    return <div />;
+  }
+}
+
+export function App() {
+  return <div>
+    <Counter initial={0} unused={1} />
+    <Counter initial={5} step={2} />
+    <Label text="hi" color="red" />
+    <WithInner />
+  </div>;
+}
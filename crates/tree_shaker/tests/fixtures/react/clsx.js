@@ -0,0 +1,33 @@
+// @react-jsx
+import clsx from "clsx";
+import { clsx as cx } from "clsx";
+import classNames from "classnames";
+import { twJoin, twMerge } from "tailwind-merge";
+
+function Button({ primary, disabled, size }) {
+  return <button className={clsx("btn", primary && "btn-primary", { disabled, [`btn-${size}`]: size })} />;
+}
+
+function cn(...inputs) {
+  return twMerge(clsx(inputs));
+}
+
+export function App() {
+  return <div className={cx("a", null, false, { b: true, c: 0 })}>
+    <Button primary size="lg" />
+    <Button disabled={false} />
+    <span className={classNames("x", { y: true })} />
+    <span className={twJoin("p-2", false, "m-1")} />
+    <span className={cn("p-2", "p-4")} />
+    <span className={clsx(["nested", "array"])} />
+  </div>;
+}
+
+export const nullish = classNames("a", null, undefined, false, 0, "");
+
+export function maybeNullish(unknown) {
+  return clsx("a", unknown ? null : { b: true });
+}
+
+export const unordered = clsx("a", { b: true, b2: 1 });
+export const joinedObject = twJoin("a", { b: true });
@@ -0,0 +1,52 @@
+// @react-jsx
+
+import React from 'react';
+
+export function Counter() {
+  const [count, setCount] = React.useState(0);
+  const [unusedState, setUnusedState] = React.useState(() => expensive());
+  const [value] = React.useState(1);
+  const [state, dispatch] = React.useReducer((s, a) => s + a, 0);
+  const inputRef = React.useRef(null);
+  const unusedRef = React.useRef(null);
+
+  React.useEffect(() => {
+    document.title = `Count: ${count}`;
+  }, [count]);
+
+  React.useLayoutEffect(() => {
+    inputRef.current.focus();
+    return () => cleanup();
+  }, []);
+
+  const unusedCallback = React.useCallback(() => effect(), []);
+  const onClick = React.useCallback(() => setCount(count + 1), [count]);
+
+  return (
+    <div onClick={onClick}>
+      <input ref={inputRef} />
+      {count}
+      {state}
+      {value}
+      <button onClick={() => dispatch(1)} />
+    </div>
+  );
+}
+
+export function OnlySetterEscapes() {
+  const [ready, setReady] = React.useState(false);
+  const timer = React.useRef(null);
+  return () => {
+    setReady(true);
+    timer.current = 1;
+  };
+}
+
+export function OnlyUpdaterEscapes() {
+  const [, setCount] = React.useState(0);
+  const [, dispatch] = React.useReducer((s, a) => s + a, 10);
+  return () => {
+    setCount(c => c + 1);
+    dispatch(1);
+  };
+}
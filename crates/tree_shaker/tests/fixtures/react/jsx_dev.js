@@ -0,0 +1,16 @@
+import { jsxDEV, Fragment } from "react/jsx-dev-runtime";
+
+const _jsxFileName = "/src/App.jsx";
+
+function Comp({ x, y }) {
+  const unused = jsxDEV("div", { b: effect() }, void 0, false, { fileName: _jsxFileName, lineNumber: 4 }, this);
+  return jsxDEV("div", {
+    children: [x, y && jsxDEV("div", { children: "Lots of things never rendered" }, void 0, false, { fileName: _jsxFileName, lineNumber: 9 }, this)]
+  }, void 0, true, { fileName: _jsxFileName, lineNumber: 7 }, this);
+}
+
+export function main() {
+  return jsxDEV(Fragment, {
+    children: jsxDEV(Comp, { x: 1 }, void 0, false, { fileName: _jsxFileName, lineNumber: 15 }, this)
+  }, "key", false, { fileName: _jsxFileName, lineNumber: 14 }, this);
+}
@@ -0,0 +1,30 @@
+// @react-jsx
+import { lazy, Suspense, startTransition } from "react";
+
+const Home = lazy(() => import("./Home"));
+const Settings = lazy(() => import("./Settings"));
+const Unused = lazy(() => import("./Unused"));
+
+function Spinner({ size }) {
+  return <div>{size}</div>;
+}
+
+export function App({ route }) {
+  const unusedPage = <Settings />;
+  return (
+    <Suspense fallback={<Spinner size={1} />}>
+      {route === "home" ? <Home title="home" /> : null}
+    </Suspense>
+  );
+}
+
+export function navigate(setRoute) {
+  startTransition(() => {
+    setRoute("settings");
+  });
+  startTransition(() => 1);
+  startTransition(() => {
+    setRoute("home");
+    return { page: "home" };
+  });
+}
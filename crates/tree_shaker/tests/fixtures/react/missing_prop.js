@@ -0,0 +1,11 @@
+// @react-jsx
+function Flag({ on, off }) {
+  return <i>{String(on)}{String(off)}</i>;
+}
+
+export function App(x, y) {
+  return <>
+    <Flag on={x} />
+    <Flag off={y} />
+  </>;
+}
@@ -0,0 +1,28 @@
+// @react-jsx
+import { createRoot } from "react-dom/client";
+import { createPortal, flushSync } from "react-dom";
+import { renderToString } from "react-dom/server";
+
+function Modal({ title, unused }) {
+  return <h1>{title}</h1>;
+}
+
+function ServerApp() {
+  log("rendered on the server");
+  return <div />;
+}
+
+function App({ name }) {
+  const portal = createPortal(<Modal title="hello" unused={1} />, document.body);
+  return <main>
+    {name}
+    {portal}
+  </main>;
+}
+
+const html = renderToString(<ServerApp />);
+
+const root = createRoot(document.getElementById("root"));
+flushSync(() => {
+  root.render(<App name="world" />);
+});
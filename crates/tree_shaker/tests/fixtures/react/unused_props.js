@@ -0,0 +1,26 @@
+// @react-jsx
+import { createElement, memo, useRef } from "react";
+
+function Leaf(props) {
+  return <div>{props.a}</div>;
+}
+
+function Middle({ a, legacy, ...rest }) {
+  return <Leaf {...rest} />;
+}
+
+const Memoized = memo(function Memoized({ a }) {
+  return <span>{a}</span>;
+});
+
+export function main(v, list) {
+  const ref = useRef(null);
+  return [
+    <Leaf a={v} b={v} c="unused" />,
+    <Middle a={v} legacy={v} b={v} />,
+    <Memoized key="k" a={v} b={v} ref={ref} />,
+    list.map((item) => <Leaf key={item} a={item} b={() => item} />),
+    createElement(Leaf, { key: "k", a: v, b: v }),
+    <Leaf a={v} b={effect()} />,
+  ];
+}
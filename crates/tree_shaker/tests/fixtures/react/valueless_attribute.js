@@ -0,0 +1,11 @@
+// @react-jsx
+function Input({ disabled }) {
+  return <input disabled={disabled} />;
+}
+
+export function App(x) {
+  return <>
+    <Input disabled />
+    <Input disabled={x} />
+  </>;
+}
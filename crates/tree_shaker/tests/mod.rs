@@ -1,17 +1,93 @@
 use insta::{assert_snapshot, glob};
 use oxc::{codegen::CodegenOptions, minifier::MinifierOptions};
+use regex::Regex;
 use std::fs;
-use tree_shaker::{tree_shake, TreeShakeConfig, TreeShakeOptions};
+use tree_shaker::{tree_shake, TreeShakeConfig, TreeShakeJsxPreset, TreeShakeOptions};
 
 fn do_tree_shake(input: String) -> String {
   let do_minify = input.contains("@minify");
-  let react_jsx = input.contains("@react-jsx");
+  let jsx = if input.contains("@react-jsx") {
+    TreeShakeJsxPreset::React
+  } else if input.contains("@preact-jsx") {
+    TreeShakeJsxPreset::Preact
+  } else if input.contains("@vue-jsx") {
+    TreeShakeJsxPreset::Vue
+  } else if input.contains("@solid-jsx") {
+    TreeShakeJsxPreset::Solid
+  } else {
+    TreeShakeJsxPreset::None
+  };
+  let manual_pure_functions = input
+    .lines()
+    .find_map(|line| line.strip_prefix("// @manual-pure-functions "))
+    .map(|paths| paths.split_whitespace().map(String::from).collect())
+    .unwrap_or_default();
+  let define = input
+    .lines()
+    .find_map(|line| line.strip_prefix("// @define "))
+    .map(|pairs| {
+      pairs
+        .split_whitespace()
+        .map(|pair| {
+          let (key, value) = pair.split_once('=').unwrap();
+          (key.to_string(), value.to_string())
+        })
+        .collect()
+    })
+    .unwrap_or_default();
+  let import_meta = input
+    .lines()
+    .find_map(|line| line.strip_prefix("// @import-meta "))
+    .map(|pairs| {
+      pairs
+        .split_whitespace()
+        .map(|pair| match pair.split_once('=') {
+          Some((key, value)) => (key.to_string(), Some(value.to_string())),
+          None => (pair.to_string(), None),
+        })
+        .collect()
+    })
+    .unwrap_or_default();
+  let mangle_reserved = input
+    .lines()
+    .find_map(|line| line.strip_prefix("// @mangle-reserved "))
+    .map(|names| names.split_whitespace().map(String::from).collect())
+    .unwrap_or_default();
+  let mangle_include = input
+    .lines()
+    .find_map(|line| line.strip_prefix("// @mangle-include "))
+    .map(|regex| Regex::new(regex).unwrap());
+  let mangle_exclude = input
+    .lines()
+    .find_map(|line| line.strip_prefix("// @mangle-exclude "))
+    .map(|regex| Regex::new(regex).unwrap());
+  let mangle_cache = input
+    .lines()
+    .find_map(|line| line.strip_prefix("// @mangle-cache "))
+    .map(|pairs| {
+      pairs
+        .split_whitespace()
+        .map(|pair| {
+          let (key, value) = pair.split_once('=').unwrap();
+          (key.to_string(), value.to_string())
+        })
+        .collect()
+    })
+    .unwrap_or_default();
   let result = tree_shake(
     input,
     TreeShakeOptions {
-      config: TreeShakeConfig::recommended().with_react_jsx(react_jsx),
+      config: TreeShakeConfig::recommended()
+        .with_jsx(jsx)
+        .with_manual_pure_functions(manual_pure_functions)
+        .with_define(define)
+        .with_import_meta(import_meta)
+        .with_mangle_reserved(mangle_reserved)
+        .with_mangle_include(mangle_include)
+        .with_mangle_exclude(mangle_exclude),
       minify_options: do_minify.then(|| MinifierOptions { mangle: None, ..Default::default() }),
       codegen_options: CodegenOptions::default(),
+      mangle_cache,
     },
   );
   result.codegen_return.code
@@ -3,7 +3,7 @@
 
 /* auto-generated by NAPI-RS */
 
-export declare function treeShake(input: string, preset: 'safest' | 'recommended' | 'smallest' | 'disabled', minify: boolean): TreeShakeResultBinding
+export declare function treeShake(input: string, preset: 'safest' | 'recommended' | 'smallest' | 'disabled', minify: boolean, config?: string): TreeShakeResultBinding
 export declare class TreeShakeResultBinding {
   output: string
   diagnostics: Array<string>
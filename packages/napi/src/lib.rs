@@ -12,25 +12,33 @@ pub struct TreeShakeResultBinding {
 }
 
 #[napi(
-  ts_args_type = "input: string, preset: 'safest' | 'recommended' | 'smallest' | 'disabled', minify: boolean"
+  ts_args_type = "input: string, preset: 'safest' | 'recommended' | 'smallest' | 'disabled', minify: boolean, config?: string"
 )]
-pub fn tree_shake(source_text: String, preset: String, minify: bool) -> TreeShakeResultBinding {
+pub fn tree_shake(
+  source_text: String,
+  preset: String,
+  minify: bool,
+  config: Option<String>,
+) -> napi::Result<TreeShakeResultBinding> {
+  let mut tree_shake_config = tree_shaker::TreeShakeConfig::preset(&preset).ok_or_else(|| {
+    napi::Error::new(napi::Status::InvalidArg, format!("Invalid tree shake option {}", preset))
+  })?;
+  if let Some(config) = config {
+    tree_shake_config = tree_shake_config
+      .with_json(&config)
+      .map_err(|e| napi::Error::new(napi::Status::InvalidArg, e))?;
+  }
   let result = tree_shaker::tree_shake(
     source_text,
     tree_shaker::TreeShakeOptions {
-      config: match preset.as_str() {
-        "safest" => tree_shaker::TreeShakeConfig::safest(),
-        "recommended" => tree_shaker::TreeShakeConfig::recommended(),
-        "smallest" => tree_shaker::TreeShakeConfig::smallest(),
-        "disabled" => tree_shaker::TreeShakeConfig::disabled(),
-        _ => panic!("Invalid tree shake option {}", preset),
-      },
+      config: tree_shake_config,
       minify_options: minify.then(|| MinifierOptions { mangle: None, ..Default::default() }),
       codegen_options: CodegenOptions { minify, ..Default::default() },
+      mangle_cache: Default::default(),
     },
   );
-  TreeShakeResultBinding {
+  Ok(TreeShakeResultBinding {
     output: result.codegen_return.code,
     diagnostics: result.diagnostics.into_iter().collect(),
-  }
+  })
 }
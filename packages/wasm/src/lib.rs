@@ -18,21 +18,22 @@ pub fn tree_shake(
   preset: String,
   do_minify: bool,
   always_inline_literal: bool,
-) -> Result {
+  config: Option<String>,
+) -> std::result::Result<Result, JsError> {
   console_error_panic_hook::set_once();
 
+  let mut tree_shake_config = tree_shaker::TreeShakeConfig::preset(&preset)
+    .ok_or_else(|| JsError::new(&format!("Invalid preset {}", preset)))?
+    .with_react_jsx(true)
+    .with_always_inline_literal(always_inline_literal);
+  if let Some(config) = config {
+    tree_shake_config = tree_shake_config.with_json(&config).map_err(|e| JsError::new(&e))?;
+  }
+
   let result = tree_shaker::tree_shake(
     source_text,
     tree_shaker::TreeShakeOptions {
-      config: match preset.as_str() {
-        "recommended" => tree_shaker::TreeShakeConfig::recommended(),
-        "smallest" => tree_shaker::TreeShakeConfig::smallest(),
-        "safest" => tree_shaker::TreeShakeConfig::safest(),
-        "disabled" => tree_shaker::TreeShakeConfig::disabled(),
-        _ => unreachable!("Invalid preset {}", preset),
-      }
-      .with_react_jsx(true)
-      .with_always_inline_literal(always_inline_literal),
+      config: tree_shake_config,
       minify_options: do_minify.then_some({
         MinifierOptions {
           mangle: Some(MangleOptions { top_level: true, ..Default::default() }),
@@ -44,10 +45,11 @@ pub fn tree_shake(
         comments: !do_minify,
         ..Default::default()
       },
+      mangle_cache: Default::default(),
     },
   );
-  Result {
+  Ok(Result {
     output: result.codegen_return.code,
     diagnostics: result.diagnostics.into_iter().collect(),
-  }
+  })
 }
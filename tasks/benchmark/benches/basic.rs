@@ -9,6 +9,7 @@ fn run_tree_shaker(source_text: String) -> String {
       config: TreeShakeConfig::recommended(),
       minify_options: None,
       codegen_options: Default::default(),
+      mangle_cache: Default::default(),
     },
   );
 
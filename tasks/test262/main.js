@@ -7,7 +7,7 @@ import * as prettier from 'prettier'
 const input = readFileSync('./input.js', 'utf8');
 
 const start = Date.now();
-const result = treeShake(input, "recommended", false, false);
+const result = treeShake(input, "recommended", false);
 console.log('Time:', Date.now() - start + 'ms');
 
 writeFileSync('./output.js', result.output);